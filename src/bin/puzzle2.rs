@@ -1,122 +1,32 @@
-use std::cmp::max;
+use std::env;
 
-use lazy_static::lazy_static;
-use regex::Regex;
-
-#[derive(Debug)]
-struct Round {
-    red: u32,
-    blue: u32,
-    green: u32,
-}
-
-#[derive(Debug)]
-struct Game {
-    rounds: Vec<Round>,
-}
-
-lazy_static! {
-    static ref RED_PATTERN: Regex = Regex::new("(\\d+) red").unwrap();
-    static ref BLUE_PATTERN: Regex = Regex::new("(\\d+) blue").unwrap();
-    static ref GREEN_PATTERN: Regex = Regex::new("(\\d+) green").unwrap();
-}
-
-fn check_regex_and_return_match(re: &Regex, haystack: &str) -> Option<u32> {
-    if let Some(cap) = re.captures(haystack) {
-        if let Some(value) = cap.get(1) {
-            return Some(value.as_str().parse().unwrap());
-        }
-    }
-
-    None
-}
-
-impl From<&str> for Round {
-    fn from(val: &str) -> Self {
-        Round {
-            red: check_regex_and_return_match(&RED_PATTERN, val).unwrap_or(0),
-            green: check_regex_and_return_match(&GREEN_PATTERN, val).unwrap_or(0),
-            blue: check_regex_and_return_match(&BLUE_PATTERN, val).unwrap_or(0),
-        }
-    }
-}
-
-impl Game {
-    fn is_game_possible(&self, red_max: u32, blue_max: u32, green_max: u32) -> bool {
-        for r in &self.rounds {
-            if r.red > red_max {
-                return false;
-            }
-
-            if r.blue > blue_max {
-                return false;
-            }
-
-            if r.green > green_max {
-                return false;
-            }
-        }
-
-        true
-    }
-
-    fn minimum_set(&self) -> Round {
-        let mut red_min = 0;
-        let mut blue_min = 0;
-        let mut green_min = 0;
-
-        for r in &self.rounds {
-            red_min = max(red_min, r.red);
-            blue_min = max(blue_min, r.blue);
-            green_min = max(green_min, r.green);
-        }
-
-        Round {
-            red: red_min,
-            blue: blue_min,
-            green: green_min,
-        }
-    }
-}
+use rust_aoc_2023::utils::parse::key_values;
+use rust_aoc_2023::y2023::days::puzzle2::{self, Cubes};
 
 fn main() {
     let input = include_str!("../../inputs/puzzle2_sample.txt");
     // let input = include_str!("../../inputs/puzzle2.txt");
 
-    let games: Vec<Game> = input
-        .lines()
-        .map(|line| {
-            let parts: Vec<&str> = line.split(':').collect();
-            let rounds: Vec<&str> = parts[1].split(';').collect();
-
-            Game {
-                rounds: rounds.iter().map(|&r| r.into()).collect(),
-            }
-        })
-        .collect();
-
-    let part1: usize = games
-        .iter()
-        .enumerate()
-        .map(|(i, g)| {
-            if g.is_game_possible(12, 14, 13) {
-                i + 1
-            } else {
-                0
-            }
-        })
-        .sum();
-
-    println!("-- Part 1: {part1}");
-
-    let part2: u32 = games
-        .iter()
-        .map(|g| {
-            let min_set = g.minimum_set();
-
-            min_set.red * min_set.blue * min_set.green
-        })
-        .sum();
+    // e.g. `--bag red=12,green=13,blue=14,teal=3` to see how the games fare with another bag.
+    if let Some(spec) = env::args().skip_while(|a| a != "--bag").nth(1) {
+        let bag: Cubes = key_values::<u32>(&spec)
+            .unwrap()
+            .into_iter()
+            .map(|(colour, n)| (colour.to_owned(), n))
+            .collect();
+        let games = puzzle2::parse_games(input).unwrap();
+
+        println!("-- Minimal bag: {}", puzzle2::describe(&puzzle2::minimal_bag(&games)));
+        for excess in puzzle2::violations(&games, &bag) {
+            println!("Game {} goes over by {}", excess.game, puzzle2::describe(&excess.cubes));
+        }
+        for (id, probabilities) in puzzle2::round_probabilities(&games, &bag) {
+            let rounds = probabilities.iter().map(|p| format!("{p:.4}")).collect::<Vec<_>>();
+            println!("Game {id} rounds: {}", rounds.join(", "));
+        }
+        return;
+    }
 
-    println!("-- Part 2: {part2}");
+    println!("-- Part 1: {}", puzzle2::part1(input));
+    println!("-- Part 2: {}", puzzle2::part2(input));
 }
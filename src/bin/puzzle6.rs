@@ -1,70 +1,29 @@
-use itertools::Itertools;
+use std::env;
+
+use rust_aoc_2023::y2023::days::puzzle6::{self, Speed};
 
 fn main() {
     // let input = include_str!("../../inputs/puzzle6_sample.txt");
     let input = include_str!("../../inputs/puzzle6.txt");
 
-    let lines = input.lines().collect_vec();
-
-    let [ref times, ref distances] = lines
-        .iter()
-        .map(|s| {
-            s.split(':')
-                .nth(1)
-                .unwrap()
-                .split_ascii_whitespace()
-                .map(|s| s.parse().unwrap())
-                .collect_vec()
-        })
-        .collect_vec()[..]
-    else {
-        unreachable!()
-    };
-
-    let ways_to_beat = calc_ways_to_beat(times, distances);
-
-    let ans1: u128 = ways_to_beat.iter().product();
-
-    println!("-- Part 1 Answer: {ans1}");
-
-    let [time, distance] = lines
-        .iter()
-        .map(|s| {
-            s.split(':')
-                .nth(1)
-                .unwrap()
-                .replace(' ', "")
-                .parse()
-                .unwrap()
-        })
-        .collect_vec()[..]
-    else {
-        unreachable!()
-    };
-
-    let ways_to_beat = calc_ways_to_beat(&[time], &[distance]);
-
-    let ans2: u128 = ways_to_beat.iter().product();
-    println!("-- Part 2 Answer: {ans2}");
-}
-
-fn calc_ways_to_beat(times: &[u128], distances: &[u128]) -> Vec<u128> {
-    let mut ways_to_beat: Vec<u128> = vec![];
-
-    for (i, &t) in times.iter().enumerate() {
-        let ds: Vec<u128> = time_to_distances(t);
-
-        ways_to_beat.push(ds.iter().filter(|&d| d > &distances[i]).count() as u128);
+    // e.g. `--holds --per-ms 2 --cap 40` to see which holds win with a faster boat that tops out.
+    if env::args().any(|a| a == "--holds") {
+        let flag = |name: &str| env::args().skip_while(|a| a != name).nth(1).map(|v| v.parse().unwrap());
+        let per_ms = flag("--per-ms").unwrap_or(1);
+        let speed = match flag("--cap") {
+            Some(max) => Speed::Capped { per_ms, max },
+            None => Speed::Linear { per_ms },
+        };
+
+        for race in puzzle6::parse_races(input).into_iter().chain([puzzle6::parse_long_race(input)]) {
+            let holds = race.winning_holds(speed);
+            let ranges = holds.iter().map(|h| format!("{}..={}", h.start(), h.end())).collect::<Vec<_>>();
+            let ways = race.ways_to_win(speed);
+            println!("{} ms, record {} mm: {ways} ways ({})", race.time, race.record, ranges.join(", "));
+        }
+        return;
     }
-    ways_to_beat
-}
 
-fn time_to_distances(time: u128) -> Vec<u128> {
-    (1..time)
-        .map(|t| {
-            let accel = t;
-            let time_rem = time - t;
-            accel * time_rem
-        })
-        .collect()
+    println!("-- Part 1 Answer: {}", puzzle6::part1(input));
+    println!("-- Part 2 Answer: {}", puzzle6::part2(input));
 }
@@ -1 +1,4 @@
+pub mod registry;
+pub mod runner;
 pub mod utils;
+pub mod y2023;
@@ -1,3 +1,407 @@
-fn main() {
-    println!("Hello, AoC 2023!");
+use std::borrow::Cow;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::{Command, ExitCode, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, fs, panic, thread};
+
+use rust_aoc_2023::registry;
+use rust_aoc_2023::runner::{self, differential, Day, Failure};
+use rust_aoc_2023::utils::alloc::measure;
+
+const USAGE: &str = "usage: rust-aoc-2023 [--year YEAR] run <day> <input file> [--timeout SECS]
+       rust-aoc-2023 [--year YEAR] batch <day> <input dir> [--out FILE] [--timeout SECS]
+       rust-aoc-2023 [--year YEAR] gen <day> [--seed N] [--size N]
+       rust-aoc-2023 [--year YEAR] diff <day> [--seeds N]
+       rust-aoc-2023 [--year YEAR] check <day> <input file>
+       rust-aoc-2023 list";
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let year = match take_year(&mut args) {
+        Ok(year) => year,
+        Err(code) => return code,
+    };
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(year, &args[1..]),
+        Some("batch") => batch(year, &args[1..]),
+        Some("gen") => gen(year, &args[1..]),
+        Some("diff") => diff(year, &args[1..]),
+        Some("check") => check(year, &args[1..]),
+        Some("list") => list(),
+        // Not for use by hand: `batch` runs parts it can't stop in a process of their own.
+        Some("solve-part") => solve_part(year, &args[1..]),
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Removes `--year` from the arguments wherever it is, defaulting to the latest registered year.
+fn take_year(args: &mut Vec<String>) -> Result<u32, ExitCode> {
+    let Some(i) = args.iter().position(|a| a == "--year") else {
+        return Ok(registry::latest());
+    };
+    let year = args.get(i + 1).and_then(|y| y.parse().ok());
+    args.drain(i..(i + 2).min(args.len()));
+
+    match year {
+        Some(year) if registry::year(year).is_some() => Ok(year),
+        Some(year) => {
+            eprintln!("no solvers for {year}");
+            Err(ExitCode::FAILURE)
+        }
+        None => {
+            eprintln!("{USAGE}");
+            Err(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Prints every registered year and day.
+fn list() -> ExitCode {
+    for (year, day) in registry::days() {
+        println!("{year} {day}");
+    }
+    ExitCode::SUCCESS
+}
+
+/// Reads the day and input file arguments shared by the subcommands that solve a given input.
+fn day_and_input(year: u32, args: &[String]) -> Result<(usize, Day, String), ExitCode> {
+    let day = args.first().and_then(|d| d.parse().ok());
+    let (Some(day), Some(path)) = (day, args.get(1)) else {
+        eprintln!("{USAGE}");
+        return Err(ExitCode::FAILURE);
+    };
+    let Some(solvers) = registry::day(year, day) else {
+        eprintln!("no solvers for day {day} of {year}");
+        return Err(ExitCode::FAILURE);
+    };
+    match fs::read_to_string(path) {
+        Ok(input) => Ok((day, solvers, input)),
+        Err(e) => {
+            eprintln!("cannot read {path}: {e}");
+            Err(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Reads the `--timeout` given to each part, in seconds.
+fn timeout(args: &[String]) -> Result<Option<Duration>, ExitCode> {
+    let flag = args.iter().skip_while(|a| *a != "--timeout").nth(1);
+    match flag.map(|s| s.parse::<f64>().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok())) {
+        None => Ok(None),
+        Some(Some(timeout)) => Ok(Some(timeout)),
+        Some(None) => {
+            eprintln!("{USAGE}");
+            Err(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Solves an input file, printing each answer with how long it took and, with the `alloc-stats`
+/// feature, how much heap it used. Parts whose assumptions the input breaks are skipped, and
+/// parts that run past `--timeout` report how far they got.
+fn run(year: u32, args: &[String]) -> ExitCode {
+    let (day, solvers, input) = match day_and_input(year, args) {
+        Ok(loaded) => loaded,
+        Err(code) => return code,
+    };
+    let timeout = match timeout(args) {
+        Ok(timeout) => timeout,
+        Err(code) => return code,
+    };
+
+    for (i, part) in [Some(solvers.part1), solvers.part2].into_iter().enumerate() {
+        let Some(part) = part else { continue };
+        if let Err(violation) = runner::check_part(year, day, i + 1, &input) {
+            println!("part {}: skipped, {violation}", i + 1);
+            continue;
+        }
+
+        let start = Instant::now();
+        let (answer, usage) = measure(|| runner::solve(part, &input, timeout));
+        let elapsed = start.elapsed();
+        let answer = answer.unwrap_or_else(|failure| failure.to_string());
+
+        match usage {
+            Some(usage) => println!("part {}: {answer} ({elapsed:.2?}, {usage})", i + 1),
+            None => println!("part {}: {answer} ({elapsed:.2?})", i + 1),
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Quotes a CSV field if it needs it.
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Solves one part of an input file, given as `<day> <input file> <part>`, and prints the time
+/// taken in milliseconds on the first line followed by the answer, or why there is none.
+/// Exits with failure if the solver panicked.
+fn solve_part(year: u32, args: &[String]) -> ExitCode {
+    let (_, solvers, input) = match day_and_input(year, args) {
+        Ok(loaded) => loaded,
+        Err(code) => return code,
+    };
+    let part = match args.get(2).map(String::as_str) {
+        Some("1") => Some(solvers.part1),
+        Some("2") => solvers.part2,
+        _ => None,
+    };
+    let Some(part) = part else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let answer = runner::try_solve(part.solve, &input);
+    println!("{:.3}", start.elapsed().as_secs_f64() * 1000.0);
+    match answer {
+        Ok(answer) => {
+            println!("{answer}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            println!("{}", Failure::Panicked(message));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solves part `part` of an input file in a child process, killing it if it runs past `timeout`.
+/// Returns the answer, the time taken in milliseconds and why there is no answer, as in a CSV row.
+fn solve_in_child(year: u32, day: usize, part: usize, path: &Path, timeout: Duration) -> (String, String, String) {
+    let failed = |error: String| (String::new(), String::new(), error);
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return failed(format!("cannot find the solver executable: {e}")),
+    };
+    let spawned = Command::new(exe)
+        .args(["--year", &year.to_string(), "solve-part", &day.to_string()])
+        .arg(path)
+        .arg(part.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => return failed(format!("cannot start the solver: {e}")),
+    };
+
+    // Read on the side, so that a long answer can't fill the pipe and stall the child.
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < timeout => thread::sleep(Duration::from_millis(1)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                let ms = format!("{:.3}", start.elapsed().as_secs_f64() * 1000.0);
+                return (String::new(), ms, Failure::TimedOut { partial: None }.to_string());
+            }
+            Err(e) => return failed(format!("cannot wait for the solver: {e}")),
+        }
+    };
+
+    let output = match reader.join() {
+        Ok(Ok(output)) => output,
+        _ => return failed("cannot read the solver's output".to_owned()),
+    };
+    let Some((ms, text)) = output.split_once('\n') else {
+        return failed(format!("the solver stopped without an answer ({status})"));
+    };
+    let text = text.strip_suffix('\n').unwrap_or(text).to_owned();
+    if status.success() {
+        (text, ms.to_owned(), String::new())
+    } else {
+        (String::new(), ms.to_owned(), text)
+    }
+}
+
+/// One CSV row for an input file: for each part the answer, the time taken in milliseconds and
+/// why there is no answer, if there isn't.
+///
+/// With a timeout, parts that can't be told to stop are solved in a child process that is killed
+/// when time runs out. A solver thread left running would eat into the time and memory of every
+/// row after it.
+fn batch_row(year: u32, day: usize, solvers: &Day, path: &Path, timeout: Option<Duration>) -> Vec<String> {
+    let mut row = vec![path.display().to_string()];
+    let input = fs::read_to_string(path);
+
+    for (i, part) in [Some(solvers.part1), solvers.part2].into_iter().enumerate() {
+        let outcome = match (&input, part) {
+            (_, None) => (String::new(), String::new(), String::new()),
+            (Err(e), _) => (String::new(), String::new(), format!("cannot read input: {e}")),
+            (Ok(input), Some(part)) => match runner::check_part(year, day, i + 1, input) {
+                Err(violation) => (String::new(), String::new(), violation.to_string()),
+                Ok(()) if part.until.is_none() && timeout.is_some() => {
+                    solve_in_child(year, day, i + 1, path, timeout.unwrap())
+                }
+                Ok(()) => {
+                    let start = Instant::now();
+                    let answer = runner::solve(part, input, timeout);
+                    let ms = format!("{:.3}", start.elapsed().as_secs_f64() * 1000.0);
+                    match answer {
+                        Ok(answer) => (answer, ms, String::new()),
+                        Err(failure) => (String::new(), ms, failure.to_string()),
+                    }
+                }
+            },
+        };
+        row.extend([outcome.0, outcome.1, outcome.2]);
+    }
+
+    row
+}
+
+/// Solves every file in a directory, writing a CSV row per file to stdout or `--out`. Panics,
+/// timeouts and inputs that break a part's assumptions end up in the error columns.
+fn batch(year: u32, args: &[String]) -> ExitCode {
+    let flag = |name: &str| args.iter().skip_while(|a| *a != name).nth(1);
+
+    let day = args.first().and_then(|d| d.parse().ok());
+    let (Some(day), Some(dir)) = (day, args.get(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    let Some(solvers) = registry::day(year, day) else {
+        eprintln!("no solvers for day {day} of {year}");
+        return ExitCode::FAILURE;
+    };
+    let timeout = match timeout(args) {
+        Ok(timeout) => timeout,
+        Err(code) => return code,
+    };
+    let mut paths = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|e| Some(e.ok()?.path())).filter(|p| p.is_file()).collect::<Vec<_>>(),
+        Err(e) => {
+            eprintln!("cannot read {dir}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    paths.sort();
+
+    let mut out: Box<dyn Write> = match flag("--out") {
+        Some(path) => match fs::File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("cannot create {path}: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    // Panic messages go into the CSV instead.
+    panic::set_hook(Box::new(|_| {}));
+
+    let header = "file,part1,part1_ms,part1_error,part2,part2_ms,part2_error";
+    let written = writeln!(out, "{header}").and_then(|_| {
+        paths.iter().try_for_each(|path| {
+            let row = batch_row(year, day, &solvers, path, timeout);
+            writeln!(out, "{}", row.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(","))
+        })
+    });
+
+    match written.and_then(|_| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("cannot write results: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints a random input for a day, e.g. `gen 12 --seed 7 --size 50 > inputs/puzzle12.txt`.
+fn gen(year: u32, args: &[String]) -> ExitCode {
+    let flag = |name: &str| args.iter().skip_while(|a| *a != name).nth(1);
+
+    let day = args.first().and_then(|d| d.parse().ok());
+    let seed = flag("--seed").map_or(Ok(0), |s| s.parse());
+    let size = flag("--size").map(|s| s.parse()).transpose();
+
+    let (Some(day), Ok(seed), Ok(size)) = (day, seed, size) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let generate = registry::year(year).unwrap().generate;
+    match generate(day, seed, size) {
+        Some(input) => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("no generator for day {day} of {year}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks a day's solvers against its references on small generated inputs, printing the
+/// smallest input they disagree on.
+fn diff(year: u32, args: &[String]) -> ExitCode {
+    let flag = |name: &str| args.iter().skip_while(|a| *a != name).nth(1);
+
+    let day = args.first().and_then(|d| d.parse().ok());
+    let seeds = flag("--seeds").map_or(Ok(10), |s| s.parse());
+
+    let (Some(day), Ok(seeds)) = (day, seeds) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    if registry::day(year, day).is_none() {
+        eprintln!("no solvers for day {day} of {year}");
+        return ExitCode::FAILURE;
+    }
+
+    match differential(year, day, seeds) {
+        Ok(checked) => {
+            println!("day {day}: all {checked} inputs agree");
+            ExitCode::SUCCESS
+        }
+        Err(mismatch) => {
+            println!("{mismatch}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks an input against the assumptions a day's solvers make about it, printing each one
+/// it breaks.
+fn check(year: u32, args: &[String]) -> ExitCode {
+    let (day, solvers, input) = match day_and_input(year, args) {
+        Ok(loaded) => loaded,
+        Err(code) => return code,
+    };
+
+    let violations = runner::check(year, day, &input);
+    for violation in &violations {
+        println!("{violation}");
+    }
+
+    if violations.is_empty() {
+        println!("day {day}: all {} assumptions hold", solvers.assumptions.len());
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
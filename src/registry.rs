@@ -0,0 +1,63 @@
+//! Every year with solvers, so the runner can look a day up by year and number.
+
+use crate::runner::Day;
+use crate::y2023;
+
+/// The solvers and input generators of one year.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    pub year: u32,
+    /// The solvers for a day (1-based), or `None` for days without any.
+    pub day: fn(usize) -> Option<Day>,
+    /// Generates an input for a day from a seed and an optional size, or `None` for days without
+    /// a generator.
+    pub generate: fn(usize, u64, Option<usize>) -> Option<String>,
+}
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2023,
+    day: y2023::days::day,
+    generate: y2023::generate::generate,
+}];
+
+pub fn year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent year, which the runner uses when none is given.
+pub fn latest() -> u32 {
+    YEARS.iter().map(|y| y.year).max().unwrap()
+}
+
+/// The solvers for `day` of `year`, if there are any.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::registry::day;
+///
+/// assert!(day(2023, 1).is_some());
+/// assert!(day(2023, 26).is_none());
+/// assert!(day(1999, 1).is_none());
+/// ```
+pub fn day(year: u32, day: usize) -> Option<Day> {
+    (self::year(year)?.day)(day)
+}
+
+/// Every registered `(year, day)` pair, in order.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::registry::days;
+///
+/// assert_eq!(days().count(), 25);
+/// assert_eq!(days().next(), Some((2023, 1)));
+/// ```
+pub fn days() -> impl Iterator<Item = (u32, usize)> {
+    let mut years = YEARS.to_vec();
+    years.sort_by_key(|y| y.year);
+    years
+        .into_iter()
+        .flat_map(|y| (1..=25).filter(move |&d| (y.day)(d).is_some()).map(move |d| (y.year, d)))
+}
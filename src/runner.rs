@@ -0,0 +1,280 @@
+//! Running a day's solvers: on an input with a time limit, against the assumptions they make
+//! about it, and against their references. None of it depends on the year, which is looked up in
+//! the [`registry`].
+
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::registry;
+use crate::utils::cancel::{Cancel, Cancelled};
+
+/// Solves a puzzle input, returning the answer as text.
+pub type Solver = fn(&str) -> String;
+
+/// A solver that checks a [`Cancel`] token as it goes, giving up with a partial answer.
+pub type Cancellable = fn(&str, &Cancel) -> Result<String, Cancelled<String>>;
+
+/// The solver for one part of a day and its reference.
+#[derive(Debug, Clone, Copy)]
+pub struct Part {
+    pub solve: Solver,
+    /// `None` if no reference gets through a real input, in which case the part is checked in a
+    /// test of its own rather than by [`differential`].
+    pub reference: Option<Solver>,
+    /// A version of `solve` that can be stopped early, for the slow ones.
+    pub until: Option<Cancellable>,
+}
+
+/// A property of the input that the solver for one part relies on without checking.
+#[derive(Debug, Clone, Copy)]
+pub struct Assumption {
+    pub part: usize,
+    /// What the solver assumes, completing "part N assumes ...".
+    pub description: &'static str,
+    /// Returns why `input` breaks the assumption, if it does.
+    pub check: fn(&str) -> Result<(), String>,
+}
+
+/// The parts of a day.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub part1: Part,
+    pub part2: Option<Part>,
+    /// The largest generated input size the references get through in reasonable time.
+    pub max_size: usize,
+    pub assumptions: &'static [Assumption],
+}
+
+/// An assumption of a day's solver that an input breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub day: usize,
+    pub part: usize,
+    pub description: &'static str,
+    pub reason: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} part {} assumes {}, but {}",
+            self.day, self.part, self.description, self.reason
+        )
+    }
+}
+
+/// Checks `input` against every assumption the solvers for `day` of `year` make, returning the
+/// ones it breaks. A check that panics on a malformed input counts as broken. Days without
+/// solvers have no assumptions.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::runner::check;
+///
+/// let garden = "...\n.S.\n...\n";
+/// assert!(check(2023, 21, garden).is_empty());
+///
+/// let violations = check(2023, 21, "S..\n...\n...\n");
+/// assert_eq!(violations[0].to_string(), "day 21 part 2 assumes `S` is in the centre of the garden, but `S` is at (0, 0) instead of (1, 1)");
+/// ```
+pub fn check(year: u32, day: usize, input: &str) -> Vec<Violation> {
+    let assumptions = registry::day(year, day).map_or(&[][..], |d| d.assumptions);
+    assumptions
+        .iter()
+        .filter_map(|a| {
+            let checked = panic::catch_unwind(AssertUnwindSafe(|| (a.check)(input)));
+            let reason = match checked {
+                Ok(result) => result.err(),
+                Err(payload) => Some(format!("checking it panicked ({})", panic_message(payload))),
+            };
+            reason.map(|reason| Violation {
+                day,
+                part: a.part,
+                description: a.description,
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Checks `input` against the assumptions of one part of a day, returning the first it breaks.
+pub fn check_part(year: u32, day: usize, part: usize, input: &str) -> Result<(), Violation> {
+    match check(year, day, input).into_iter().find(|v| v.part == part) {
+        Some(violation) => Err(violation),
+        None => Ok(()),
+    }
+}
+
+/// A generated input on which a solver and its reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: usize,
+    pub part: usize,
+    pub size: usize,
+    pub seed: u64,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "day {} part {}: expected {}, got {} (size {}, seed {})",
+            self.day, self.part, self.expected, self.actual, self.size, self.seed
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+/// Runs `solver`, returning its panic message instead if it panics. The panic is still
+/// reported by the panic hook.
+pub fn try_solve(solver: Solver, input: &str) -> Result<String, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input))).map_err(panic_message)
+}
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut { partial: Option<String> },
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panic: {message}"),
+            Failure::TimedOut { partial: None } => write!(f, "timed out"),
+            Failure::TimedOut { partial: Some(partial) } => write!(f, "timed out, partial answer {partial}"),
+        }
+    }
+}
+
+/// Solves `input` with `part`, giving up after `timeout` if there is one. Solvers with an
+/// [`until`](Part::until) version are told to stop and report how far they got. Others run on a
+/// thread of their own that is left behind when time runs out.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_aoc_2023::registry::day;
+/// use rust_aoc_2023::runner::{solve, Failure};
+///
+/// let part1 = day(2023, 1).unwrap().part1;
+/// assert_eq!(solve(part1, "a1b2c3\n", None), Ok("13".to_owned()));
+///
+/// let part2 = day(2023, 23).unwrap().part2.unwrap();
+/// let trails = "#.###\n#...#\n###.#\n";
+/// assert_eq!(solve(part2, trails, Some(Duration::from_secs(10))), Ok("4".to_owned()));
+/// assert_eq!(solve(part2, trails, Some(Duration::ZERO)), Err(Failure::TimedOut { partial: None }));
+/// ```
+pub fn solve(part: Part, input: &str, timeout: Option<Duration>) -> Result<String, Failure> {
+    let Some(timeout) = timeout else {
+        return try_solve(part.solve, input).map_err(Failure::Panicked);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    let cancel = Cancel::after(timeout);
+    let token = cancel.clone();
+    thread::spawn(move || {
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| match part.until {
+            Some(until) => until(&input, &token).map_err(|c| Failure::TimedOut { partial: c.partial }),
+            None => Ok((part.solve)(&input)),
+        }));
+        // The receiver is gone if the solver took too long, and nobody wants the answer then.
+        let _ = sender.send(answer.unwrap_or_else(|payload| Err(Failure::Panicked(panic_message(payload)))));
+    });
+
+    let answer = match part.until {
+        Some(_) => receiver.recv().ok(),
+        None => receiver.recv_timeout(timeout).ok(),
+    };
+    cancel.cancel();
+    answer.unwrap_or(Err(Failure::TimedOut { partial: None }))
+}
+
+/// Runs `solver`, turning a panic into an answer of its own so it shows up as a disagreement.
+fn run(solver: Solver, input: &str) -> String {
+    try_solve(solver, input).unwrap_or_else(|message| format!("panic ({message})"))
+}
+
+/// Compares each part of `day` of `year` with its reference on `seeds` generated inputs of every size from
+/// 1 up to the day's `max_size`. Sizes are tried smallest first, so the mismatch returned is
+/// the smallest failing case found. Returns the number of inputs checked otherwise.
+///
+/// A part is not compared on inputs that break its [`Assumption`]s, since the solver is not
+/// expected to get those right, and parts without a reference are not compared at all.
+///
+/// Panics if there are no solvers for the day.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::runner::differential;
+///
+/// assert_eq!(differential(2023, 4, 2), Ok(20));
+/// ```
+pub fn differential(year: u32, day: usize, seeds: u64) -> Result<usize, Mismatch> {
+    let Day { part1, part2, max_size, .. } =
+        registry::day(year, day).unwrap_or_else(|| panic!("no solvers for day {day} of {year}"));
+    let generate = registry::year(year).unwrap().generate;
+    let parts = [Some(part1), part2];
+
+    // Solver panics are reported as mismatches, so keep them off stderr while running. A panic
+    // from the generator still gets out, but only once the hook is back.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let searched = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut checked = 0;
+        let mut result = Ok(());
+        'search: for size in 1..=max_size {
+            for seed in 0..seeds {
+                let input = generate(day, seed, Some(size)).unwrap();
+                checked += 1;
+
+                for (i, part) in parts.iter().enumerate() {
+                    let Some(Part { solve, reference: Some(reference), .. }) = part else { continue };
+                    if check_part(year, day, i + 1, &input).is_err() {
+                        continue;
+                    }
+                    let expected = run(*reference, &input);
+                    let actual = run(*solve, &input);
+                    if expected != actual {
+                        result = Err(Mismatch {
+                            day,
+                            part: i + 1,
+                            size,
+                            seed,
+                            input,
+                            expected,
+                            actual,
+                        });
+                        break 'search;
+                    }
+                }
+            }
+        }
+        result.map(|_| checked)
+    }));
+
+    panic::set_hook(hook);
+    searched.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
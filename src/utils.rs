@@ -1,6 +1,20 @@
+pub mod alloc;
+pub mod anim;
+pub mod automaton;
+pub mod cancel;
+pub mod dot;
+pub mod image;
+pub mod interval;
+pub mod memo;
+pub mod parse;
+pub mod render;
+pub mod rng;
+pub mod svg;
+
+use std::fmt::Display;
 use std::ops::{Div, Mul, Rem};
 use itertools::Itertools;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 /// Returns the 8 neighbors of a given x, y coordinate without any bounds checks.
 ///
@@ -55,7 +69,8 @@ pub fn iter_neighbors_bounded8(x: i32, y: i32, max_x: i32, max_y: i32) -> Vec<(i
 }
 
 
-/// Calculate LCM of a list of numbers
+/// Calculate LCM of a list of numbers. The LCM of no numbers is 1, and of any list containing 0
+/// is 0. Only overflows if the LCM itself does not fit.
 ///
 /// # Examples
 ///
@@ -64,18 +79,22 @@ pub fn iter_neighbors_bounded8(x: i32, y: i32, max_x: i32, max_y: i32) -> Vec<(i
 ///
 /// assert_eq!(lcm(&[1, 3, 5]), 15);
 /// assert_eq!(lcm(&[1.0, 3.0, 4.0]), 12.0);
+/// assert_eq!(lcm::<u64>(&[]), 1);
+/// assert_eq!(lcm(&[u64::MAX, u64::MAX]), u64::MAX);
 /// ```
 pub fn lcm<T>(nums: &[T]) -> T
     where
-        T: Copy + PartialEq + Mul<Output=T> + Div<Output=T> + Zero + Rem<Output=T>
+        T: Copy + PartialEq + Mul<Output=T> + Div<Output=T> + Zero + One + Rem<Output=T>
 {
-    if nums.len() == 1 {
-        return nums[0];
-    }
+    let Some((&a, rest)) = nums.split_first() else {
+        return T::one();
+    };
 
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-    a * b / gcd(a, b)
+    let b = lcm(rest);
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+    a / gcd(a, b) * b
 }
 
 
@@ -133,12 +152,9 @@ pub fn iter_neighbors4_bounded(x: i64, y: i64, max_x: i64, max_y: i64) -> Vec<(i
         .collect_vec()
 }
 
-/// Print a grid to stdout. Assumes that an mxn grid is passed in.
-pub fn print_grid(grid: &[Vec<char>]) {
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            print!("{}", grid[i][j]);
-        }
-        println!();
-    }
+/// Print a grid to stdout. See [`render::GridRenderer`] for overlays, colours and other targets.
+pub fn print_grid<T: Display>(grid: &[Vec<T>]) {
+    render::GridRenderer::new()
+        .render(grid, &mut std::io::stdout().lock())
+        .unwrap();
 }
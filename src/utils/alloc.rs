@@ -0,0 +1,134 @@
+//! Heap usage counters, filled in by a counting global allocator when the `alloc-stats` feature
+//! is on. Without the feature nothing is counted and [`measure`] reports `None`.
+
+use std::fmt::{Display, Formatter};
+
+/// The heap used while running a closure under [`measure`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The most heap in use at once, above what was already in use when the closure started.
+    pub peak_bytes: usize,
+    /// Allocations and reallocations made.
+    pub allocations: usize,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.peak_bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "{} B peak", self.peak_bytes)?;
+        } else {
+            write!(f, "{size:.1} {} peak", UNITS[unit])?;
+        }
+        write!(f, ", {} allocations", self.allocations)
+    }
+}
+
+/// Runs `f`, returning its result and the heap it used, or `None` for the usage when the
+/// `alloc-stats` feature is off.
+///
+/// The counters are global, so usage from other threads running at the same time is included.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::alloc::measure;
+///
+/// let (v, usage) = measure(|| vec![0u8; 4096]);
+/// assert_eq!(v.len(), 4096);
+/// if let Some(usage) = usage {
+///     assert!(usage.peak_bytes >= 4096);
+///     assert!(usage.allocations >= 1);
+/// }
+/// ```
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let start = counting::start();
+        let result = f();
+        (result, Some(counting::usage_since(start)))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    use super::Usage;
+
+    static IN_USE: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, keeping track of the bytes in use and how many allocations there were.
+    struct Counting;
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    fn grow(bytes: usize) {
+        let in_use = IN_USE.fetch_add(bytes, Relaxed) + bytes;
+        PEAK.fetch_max(in_use, Relaxed);
+        ALLOCATIONS.fetch_add(1, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            IN_USE.fetch_sub(layout.size(), Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                IN_USE.fetch_sub(layout.size(), Relaxed);
+                grow(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    /// Resets the peak to the heap in use now, returning the counters to measure from.
+    pub fn start() -> (usize, usize) {
+        let in_use = IN_USE.load(Relaxed);
+        PEAK.store(in_use, Relaxed);
+        (in_use, ALLOCATIONS.load(Relaxed))
+    }
+
+    pub fn usage_since((in_use, allocations): (usize, usize)) -> Usage {
+        Usage {
+            peak_bytes: PEAK.load(Relaxed).saturating_sub(in_use),
+            allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+/// How often stdin is checked for a key while paused.
+const PAUSED_POLL: Duration = Duration::from_millis(20);
+
+/// Puts the terminal into unbuffered, no-echo mode with reads that never wait for input, and puts
+/// back the settings it found when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    /// Returns `None` if stdin isn't a terminal that stty can change.
+    fn enable() -> Option<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "0", "time", "0"])?;
+        Some(RawMode {
+            saved: saved.trim().to_owned(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// Runs stty on the terminal of stdin, returning what it printed if it succeeded.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The next key pressed, or `None` if there isn't one yet. Only returns straight away while a
+/// [`RawMode`] is alive.
+fn poll_key() -> Option<u8> {
+    let mut key = [0];
+    match io::stdin().read(&mut key) {
+        Ok(1) => Some(key[0]),
+        _ => None,
+    }
+}
+
+/// Redraws frames of a grid simulation in place.
+///
+/// Frames come from a step callback which is handed the frame number and returns the text of the
+/// next frame, or `None` once the simulation is over. When interactive, `space` pauses and
+/// resumes, `n` advances a single frame while paused and `q` stops the animation.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_aoc_2023::utils::anim::Animator;
+///
+/// let mut out = vec![];
+/// let mut animator = Animator::with_writer(&mut out)
+///     .delay(Duration::ZERO)
+///     .record(true);
+///
+/// let count = animator
+///     .run(|n| (n < 3).then(|| format!("{}\n", "#".repeat(n + 1))))
+///     .unwrap();
+///
+/// assert_eq!(count, 3);
+/// assert_eq!(animator.frames(), ["#\n", "##\n", "###\n"]);
+///
+/// let mut text = vec![];
+/// animator.export(&mut text).unwrap();
+/// assert!(String::from_utf8(text).unwrap().starts_with("-- Frame 0\n#\n"));
+/// ```
+pub struct Animator<W: Write> {
+    out: W,
+    delay: Duration,
+    interactive: bool,
+    record: bool,
+    frames: Vec<String>,
+}
+
+impl Animator<io::Stdout> {
+    /// An animator drawing to stdout with a 100ms frame delay.
+    pub fn new() -> Self {
+        Self::with_writer(io::stdout())
+    }
+}
+
+impl Default for Animator<io::Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Animator<W> {
+    pub fn with_writer(out: W) -> Self {
+        Animator {
+            out,
+            delay: Duration::from_millis(100),
+            interactive: false,
+            record: false,
+            frames: vec![],
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Enables keyboard controls. Keypresses are read from stdin between frames, and only if it is
+    /// a terminal.
+    pub fn interactive(mut self, enabled: bool) -> Self {
+        self.interactive = enabled;
+        self
+    }
+
+    /// Keeps a copy of every drawn frame, see [`Animator::frames`] and [`Animator::export`].
+    pub fn record(mut self, enabled: bool) -> Self {
+        self.record = enabled;
+        self
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Writes all recorded frames as plain text, each preceded by a `-- Frame N` header.
+    pub fn export<E: Write>(&self, out: &mut E) -> io::Result<()> {
+        for (i, frame) in self.frames.iter().enumerate() {
+            writeln!(out, "-- Frame {i}")?;
+            write!(out, "{frame}")?;
+        }
+        Ok(())
+    }
+
+    /// Runs the animation until `step` returns `None` or `q` is pressed.
+    /// Returns the number of frames drawn.
+    pub fn run<F>(&mut self, mut step: F) -> io::Result<usize>
+    where
+        F: FnMut(usize) -> Option<String>,
+    {
+        let raw_mode = if self.interactive { RawMode::enable() } else { None };
+        let mut paused = false;
+        let mut count = 0;
+
+        write!(self.out, "\x1b[2J")?;
+
+        while let Some(frame) = step(count) {
+            write!(self.out, "\x1b[H{frame}\x1b[J")?;
+            if raw_mode.is_some() {
+                writeln!(self.out, "frame {count} | space: pause/resume, n: step, q: quit")?;
+            }
+            self.out.flush()?;
+
+            if self.record {
+                self.frames.push(frame);
+            }
+            count += 1;
+
+            if raw_mode.is_some() {
+                loop {
+                    match poll_key() {
+                        Some(b'q') => return Ok(count),
+                        Some(b' ') => {
+                            paused = !paused;
+                            if !paused {
+                                break;
+                            }
+                        }
+                        Some(b'n') if paused => break,
+                        Some(_) => {}
+                        None if paused => thread::sleep(PAUSED_POLL),
+                        None => break,
+                    }
+                }
+            }
+
+            if !paused {
+                thread::sleep(self.delay);
+            }
+        }
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,110 @@
+//! An Aho-Corasick automaton, for finding every occurrence of a set of patterns in one pass.
+
+use std::collections::{HashMap, VecDeque};
+
+/// One occurrence of a pattern, as byte offsets into the searched text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the pattern in the list the automaton was built from.
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    next: HashMap<u8, usize>,
+    /// The longest proper suffix of this state that is also a state.
+    fail: usize,
+    /// The patterns ending here, including those ending at a suffix of it.
+    outputs: Vec<usize>,
+}
+
+/// Matches a fixed set of patterns against text, reporting overlapping matches too.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::automaton::{Automaton, Match};
+///
+/// let automaton = Automaton::new(["eight", "two", "one"]);
+/// let matches = automaton.find_overlapping("eightwone").collect::<Vec<_>>();
+/// assert_eq!(matches, [
+///     Match { pattern: 0, start: 0, end: 5 },
+///     Match { pattern: 1, start: 4, end: 7 },
+///     Match { pattern: 2, start: 6, end: 9 },
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    states: Vec<State>,
+    lengths: Vec<usize>,
+}
+
+impl Automaton {
+    /// Builds the automaton for `patterns`. Empty patterns never match.
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = P>) -> Self {
+        let mut states = vec![State::default()];
+        let mut lengths = vec![];
+
+        for (i, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            lengths.push(pattern.len());
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut current = 0;
+            for &byte in pattern {
+                current = match states[current].next.get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        states.push(State::default());
+                        let next = states.len() - 1;
+                        states[current].next.insert(byte, next);
+                        next
+                    }
+                };
+            }
+            states[current].outputs.push(i);
+        }
+
+        // Breadth first, so the fail state of every state is done before its children.
+        let mut queue = states[0].next.values().copied().collect::<VecDeque<_>>();
+        while let Some(current) = queue.pop_front() {
+            let children = states[current].next.iter().map(|(&b, &s)| (b, s)).collect::<Vec<_>>();
+            for (byte, child) in children {
+                let mut fail = states[current].fail;
+                while fail != 0 && !states[fail].next.contains_key(&byte) {
+                    fail = states[fail].fail;
+                }
+                let fail = states[fail].next.get(&byte).copied().filter(|&s| s != child).unwrap_or(0);
+
+                states[child].fail = fail;
+                let inherited = states[fail].outputs.clone();
+                states[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Self { states, lengths }
+    }
+
+    /// Every match of every pattern in `text`, ordered by where they end. Matches ending at the
+    /// same place come longest first.
+    pub fn find_overlapping<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut current = 0;
+        text.bytes().enumerate().flat_map(move |(i, byte)| {
+            while current != 0 && !self.states[current].next.contains_key(&byte) {
+                current = self.states[current].fail;
+            }
+            current = self.states[current].next.get(&byte).copied().unwrap_or(0);
+
+            self.states[current].outputs.iter().map(move |&pattern| Match {
+                pattern,
+                start: i + 1 - self.lengths[pattern],
+                end: i + 1,
+            })
+        })
+    }
+}
@@ -0,0 +1,65 @@
+//! Cooperative cancellation for long running solvers, which check a [`Cancel`] token in their
+//! main loops and give up with what they have so far.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Tells a solver to stop, either when its deadline passes or when [`cancel`](Cancel::cancel)
+/// is called on any clone of the token.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_aoc_2023::utils::cancel::Cancel;
+///
+/// assert!(!Cancel::never().is_cancelled());
+/// assert!(Cancel::after(Duration::ZERO).is_cancelled());
+///
+/// let token = Cancel::never();
+/// token.clone().cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancel {
+    /// A token that is only cancelled by calling [`cancel`](Cancel::cancel).
+    pub fn never() -> Self {
+        Self::default()
+    }
+
+    /// A token that is cancelled once `timeout` has passed.
+    pub fn after(timeout: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + timeout),
+            ..Self::default()
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+}
+
+/// A solver stopped by its [`Cancel`] token, with the best answer it had found so far, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled<T> {
+    pub partial: Option<T>,
+}
+
+impl<T> Cancelled<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Cancelled<U> {
+        Cancelled {
+            partial: self.partial.map(f),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use indexmap::IndexMap;
+
+/// Graphviz node shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Ellipse,
+    Box,
+    Diamond,
+    Circle,
+    DoubleCircle,
+    Octagon,
+    Point,
+}
+
+impl Shape {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Shape::Ellipse => "ellipse",
+            Shape::Box => "box",
+            Shape::Diamond => "diamond",
+            Shape::Circle => "circle",
+            Shape::DoubleCircle => "doublecircle",
+            Shape::Octagon => "octagon",
+            Shape::Point => "point",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    shape: Shape,
+    fill: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+    highlighted: bool,
+}
+
+/// Builds a Graphviz DOT graph.
+///
+/// Nodes only need to be declared to give them a shape or colour; edges can refer to any name.
+/// Nodes and edges are written in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::dot::{Dot, Shape};
+///
+/// let mut dot = Dot::directed();
+/// dot.node("AAA", Shape::Box);
+/// dot.edge("AAA", "BBB", Some("L"));
+/// dot.edge("AAA", "CCC", Some("R"));
+/// assert_eq!(dot.highlight("AAA", "CCC"), 1);
+///
+/// assert_eq!(
+///     dot.to_string(),
+///     "digraph {\n  \"AAA\" [shape=box];\n  \"AAA\" -> \"BBB\" [label=\"L\"];\n  \"AAA\" -> \"CCC\" [label=\"R\", color=red, penwidth=2];\n}\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dot {
+    directed: bool,
+    nodes: IndexMap<String, Node>,
+    edges: Vec<Edge>,
+}
+
+impl Dot {
+    pub fn directed() -> Self {
+        Dot {
+            directed: true,
+            nodes: IndexMap::new(),
+            edges: vec![],
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Dot {
+            directed: false,
+            ..Self::directed()
+        }
+    }
+
+    /// Declares a node, or changes the shape of an already declared one.
+    pub fn node(&mut self, id: &str, shape: Shape) {
+        self.nodes
+            .entry(id.to_owned())
+            .and_modify(|n| n.shape = shape)
+            .or_insert(Node { shape, fill: None });
+    }
+
+    /// Fills a node with a Graphviz colour, declaring it as an ellipse if needed.
+    pub fn fill(&mut self, id: &str, color: &str) {
+        self.nodes
+            .entry(id.to_owned())
+            .or_insert(Node {
+                shape: Shape::Ellipse,
+                fill: None,
+            })
+            .fill = Some(color.to_owned());
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: Option<&str>) {
+        self.edges.push(Edge {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            label: label.map(ToOwned::to_owned),
+            highlighted: false,
+        });
+    }
+
+    /// Highlights every edge between `from` and `to`, in either direction for undirected graphs.
+    /// Returns the number of edges highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::dot::Dot;
+    ///
+    /// let mut dot = Dot::undirected();
+    /// dot.edge("jqt", "rhn", None);
+    /// assert_eq!(dot.highlight("rhn", "jqt"), 1);
+    /// assert!(dot.to_string().contains("\"jqt\" -- \"rhn\" [color=red, penwidth=2];"));
+    /// ```
+    pub fn highlight(&mut self, from: &str, to: &str) -> usize {
+        let directed = self.directed;
+        let mut count = 0;
+        for e in &mut self.edges {
+            let forward = e.from == from && e.to == to;
+            let backward = !directed && e.from == to && e.to == from;
+            if forward || backward {
+                e.highlighted = true;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (kind, arrow) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+        writeln!(f, "{kind} {{")?;
+
+        for (id, node) in &self.nodes {
+            write!(f, "  {} [shape={}", quote(id), node.shape.as_str())?;
+            if let Some(fill) = &node.fill {
+                write!(f, ", style=filled, fillcolor={}", quote(fill))?;
+            }
+            writeln!(f, "];")?;
+        }
+
+        for e in &self.edges {
+            let mut attrs = vec![];
+            if let Some(label) = &e.label {
+                attrs.push(format!("label={}", quote(label)));
+            }
+            if e.highlighted {
+                attrs.push("color=red, penwidth=2".to_owned());
+            }
+
+            write!(f, "  {} {arrow} {}", quote(&e.from), quote(&e.to))?;
+            if !attrs.is_empty() {
+                write!(f, " [{}]", attrs.join(", "))?;
+            }
+            writeln!(f, ";")?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
@@ -0,0 +1,249 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use num_traits::ToPrimitive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(96, 96, 96);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 180, 60);
+    pub const BLUE: Rgb = Rgb(40, 80, 220);
+}
+
+/// How values are mapped onto the heatmap gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scale {
+    Linear,
+    Log,
+}
+
+/// Maps `t` in `[0, 1]` onto a black → red → yellow → white gradient.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::image::{heat_color, Rgb};
+///
+/// assert_eq!(heat_color(0.0), Rgb::BLACK);
+/// assert_eq!(heat_color(1.0), Rgb::WHITE);
+/// assert_eq!(heat_color(2.0), Rgb::WHITE);
+/// ```
+pub fn heat_color(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0) * 3.0;
+    let channel = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Rgb(channel(t), channel(t - 1.0), channel(t - 2.0))
+}
+
+/// An RGB raster, addressed by `(row, col)` like the puzzle grids.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::image::{Image, Rgb};
+///
+/// let grid = vec![vec!['#', '.'], vec!['.', '#']];
+/// let image = Image::from_grid(&grid, |c| if *c == '#' { Rgb::BLACK } else { Rgb::WHITE });
+///
+/// let mut ppm = vec![];
+/// image.write_ppm(&mut ppm).unwrap();
+/// assert_eq!(&ppm[..11], b"P6\n2 2\n255\n");
+/// assert_eq!(&ppm[11..14], &[0, 0, 0]);
+///
+/// let mut png = vec![];
+/// image.scaled(4).write_png(&mut png).unwrap();
+/// assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per grid cell, coloured by `palette`.
+    pub fn from_grid<T>(grid: &[Vec<T>], palette: impl Fn(&T) -> Rgb) -> Self {
+        let height = grid.len();
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let mut image = Image::new(width, height, Rgb::BLACK);
+        for (i, row) in grid.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                image.set(i, j, palette(cell));
+            }
+        }
+        image
+    }
+
+    /// A heatmap of a position → value map, scaled between zero and the largest value.
+    /// Positions without a value are left black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use rust_aoc_2023::utils::image::{heat_color, Image, Rgb, Scale};
+    ///
+    /// let counts: HashMap<(usize, usize), usize> = HashMap::from([((0, 0), 1), ((0, 1), 4)]);
+    /// let image = Image::heatmap(2, 1, counts, Scale::Linear);
+    ///
+    /// assert_eq!(image.get(0, 0), Some(heat_color(0.25)));
+    /// assert_eq!(image.get(0, 1), Some(Rgb::WHITE));
+    /// ```
+    pub fn heatmap<V: ToPrimitive>(
+        width: usize,
+        height: usize,
+        values: impl IntoIterator<Item = ((usize, usize), V)>,
+        scale: Scale,
+    ) -> Self {
+        let transform = |v: f64| match scale {
+            Scale::Linear => v,
+            Scale::Log => v.max(0.0).ln_1p(),
+        };
+        let values: Vec<((usize, usize), f64)> = values
+            .into_iter()
+            .map(|(p, v)| (p, transform(v.to_f64().unwrap_or(0.0))))
+            .collect();
+        let max = values.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+
+        let mut image = Image::new(width, height, Rgb::BLACK);
+        for ((i, j), v) in values {
+            let t = if max > 0.0 { v / max } else { 0.0 };
+            image.set(i, j, heat_color(t));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<Rgb> {
+        (row < self.height && col < self.width).then(|| self.pixels[row * self.width + col])
+    }
+
+    /// Sets a pixel, ignoring positions outside the image.
+    pub fn set(&mut self, row: usize, col: usize, color: Rgb) {
+        if row < self.height && col < self.width {
+            self.pixels[row * self.width + col] = color;
+        }
+    }
+
+    /// Enlarges every pixel into a `factor` × `factor` block, useful for small sample grids.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let mut image = Image::new(self.width * factor, self.height * factor, Rgb::BLACK);
+        for i in 0..image.height {
+            for j in 0..image.width {
+                image.pixels[i * image.width + j] = self.pixels[(i / factor) * self.width + j / factor];
+            }
+        }
+        image
+    }
+
+    /// Writes a binary (P6) PPM.
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for p in &self.pixels {
+            out.write_all(&[p.0, p.1, p.2])?;
+        }
+        Ok(())
+    }
+
+    /// Writes an uncompressed PNG.
+    pub fn write_png<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = vec![];
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // 8 bit depth, truecolour, default compression, filter and no interlace.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_png_chunk(out, b"IHDR", &header)?;
+
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            for p in row {
+                raw.extend_from_slice(&[p.0, p.1, p.2]);
+            }
+        }
+        write_png_chunk(out, b"IDAT", &zlib_stored(&raw))?;
+        write_png_chunk(out, b"IEND", &[])
+    }
+
+    /// Saves the image, as PNG if the path ends in `.png` and as PPM otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut out = BufWriter::new(File::create(path)?);
+        if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("png")) {
+            self.write_png(&mut out)?;
+        } else {
+            self.write_ppm(&mut out)?;
+        }
+        out.flush()
+    }
+}
+
+fn write_png_chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut result = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        result.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        result.push(u8::from(is_last));
+        result.extend_from_slice(&len.to_le_bytes());
+        result.extend_from_slice(&(!len).to_le_bytes());
+        result.extend_from_slice(block);
+    }
+    result.extend_from_slice(&adler32(data).to_be_bytes());
+    result
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &b in bytes {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
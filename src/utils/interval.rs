@@ -0,0 +1,56 @@
+//! Half-open integer intervals, for mapping whole ranges of numbers at once.
+
+/// The numbers from `start` up to but not including `end`. Intervals with `end <= start` are
+/// empty.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::interval::Interval;
+///
+/// let a = Interval::with_len(79, 14);
+/// assert_eq!(a, Interval::new(79, 93));
+/// assert!(a.contains(92) && !a.contains(93));
+///
+/// assert_eq!(a.intersect(Interval::new(50, 98)), a);
+/// assert!(a.intersect(Interval::new(0, 79)).is_empty());
+/// assert_eq!(a.shift(2), Interval::new(81, 95));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// The `len` numbers from `start` on.
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The numbers in both intervals, which may be empty.
+    pub fn intersect(&self, other: Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The interval moved up by `offset`, or down for a negative one.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
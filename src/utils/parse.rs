@@ -0,0 +1,235 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref INT_PAT: Regex = Regex::new("-?\\d+").unwrap();
+}
+
+/// A parse failure along with the text that caused it and, when known, its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Attaches a line number, unless the error already carries one.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}: `{}`", self.message, self.text),
+            None => write!(f, "{}: `{}`", self.message, self.text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_value<T>(s: &str, context: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError::new(context, format!("invalid value `{}`: {e}", s.trim())))
+}
+
+/// Parses every line of `input` with `f`, tagging any error with its line number.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::{ints, parse_lines};
+///
+/// let rows = parse_lines("1 2\n3 -4\n", ints::<i32>).unwrap();
+/// assert_eq!(rows, vec![vec![1, 2], vec![3, -4]]);
+///
+/// let err = parse_lines("1\n99999999999", ints::<i32>).unwrap_err();
+/// assert_eq!(err.line, Some(2));
+/// ```
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Extracts all (optionally negative) integers from a line, ignoring everything else.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::ints;
+///
+/// assert_eq!(ints::<i64>("Time:  7 15   30"), Ok(vec![7, 15, 30]));
+/// assert_eq!(ints::<i64>("19, 13, 30 @ -2,  1, -2"), Ok(vec![19, 13, 30, -2, 1, -2]));
+/// assert!(ints::<u8>("300").is_err());
+/// ```
+pub fn ints<T>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    INT_PAT
+        .find_iter(line)
+        .map(|m| parse_value(m.as_str(), line))
+        .collect()
+}
+
+/// Splits input into blank-line separated blocks. Tolerates CRLF line endings, blank lines
+/// containing only whitespace and any number of trailing newlines.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::blocks;
+///
+/// assert_eq!(blocks("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+/// assert_eq!(blocks("a\r\nb\r\n\r\n\r\nc\r\n\r\n"), vec!["a\r\nb", "c"]);
+/// assert!(blocks("\n\n").is_empty());
+/// ```
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for raw_line in input.split_inclusive('\n') {
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        if line.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                result.push(&input[start..end]);
+            }
+        } else {
+            let end = offset + line.len();
+            block = Some(block.map_or((offset, end), |(start, _)| (start, end)));
+        }
+        offset += raw_line.len();
+    }
+
+    if let Some((start, end)) = block {
+        result.push(&input[start..end]);
+    }
+
+    result
+}
+
+/// Splits a `label: rest` line at its first colon, e.g. `Card 1: 41 48 | 83 86`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::split_label;
+///
+/// assert_eq!(split_label("Game 4: 3 blue"), Ok(("Game 4", "3 blue")));
+/// assert!(split_label("3 blue").is_err());
+/// ```
+pub fn split_label(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(':')
+        .map(|(label, rest)| (label.trim(), rest.trim()))
+        .ok_or_else(|| ParseError::new(line, "expected `label: value`"))
+}
+
+/// Parses a `key=value` record such as `{x=787,m=2655}` or `a=1, b=2`, keeping the original
+/// order. Surrounding braces and whitespace are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::key_values;
+///
+/// assert_eq!(key_values::<u32>("{x=787,m=2655}"), Ok(vec![("x", 787), ("m", 2655)]));
+/// assert!(key_values::<u32>("{x=787,m}").is_err());
+/// ```
+pub fn key_values<T>(record: &str) -> Result<Vec<(&str, T)>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let inner = record
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}');
+
+    inner
+        .split(',')
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(record, format!("expected `key=value`, found `{}`", field.trim())))?;
+            Ok((key.trim(), parse_value(value, record)?))
+        })
+        .collect()
+}
+
+/// Parses exactly `N` values separated by `sep`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::parse::tuple;
+///
+/// let [x, y, z] = tuple::<usize, 3>("1,0,1", ',').unwrap();
+/// assert_eq!((x, y, z), (1, 0, 1));
+/// assert!(tuple::<usize, 3>("1,0", ',').is_err());
+/// ```
+pub fn tuple<T, const N: usize>(s: &str, sep: char) -> Result<[T; N], ParseError>
+where
+    T: FromStr + Debug,
+    T::Err: Display,
+{
+    let values: Vec<T> = s.split(sep).map(|v| parse_value(v, s)).try_collect()?;
+    let count = values.len();
+    values
+        .try_into()
+        .map_err(|_| ParseError::new(s, format!("expected {N} values separated by `{sep}`, found {count}")))
+}
+
+/// Returns all capture groups of `re` (excluding the whole match) for a line.
+///
+/// # Examples
+///
+/// ```
+/// use regex::Regex;
+/// use rust_aoc_2023::utils::parse::captures;
+///
+/// let re = Regex::new("(\\w+) = \\((\\w+), (\\w+)\\)").unwrap();
+/// assert_eq!(captures(&re, "AAA = (BBB, CCC)"), Ok(vec!["AAA", "BBB", "CCC"]));
+/// assert!(captures(&re, "AAA").is_err());
+/// ```
+pub fn captures<'a>(re: &Regex, line: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let caps = re
+        .captures(line)
+        .ok_or_else(|| ParseError::new(line, format!("does not match `{}`", re.as_str())))?;
+
+    Ok(caps
+        .iter()
+        .skip(1)
+        .map(|m| m.map_or("", |m| m.as_str()))
+        .collect_vec())
+}
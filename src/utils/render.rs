@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::ops::Range;
+
+/// ANSI foreground colours usable for overlays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Overlay {
+    positions: HashSet<(usize, usize)>,
+    glyph: Option<char>,
+    color: Option<Color>,
+}
+
+/// Renders a grid of any `Display` cells, with optional overlays, colours and cropping.
+///
+/// Overlays are applied in the order they were added, so later overlays win where they overlap.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::render::{Color, GridRenderer};
+///
+/// let grid = vec![vec!['.', '.', '#'], vec!['.', '#', '.'], vec!['.', '.', '.']];
+/// let mut out = vec![];
+///
+/// GridRenderer::new()
+///     .overlay([(0, 0), (1, 0), (2, 0)], 'O')
+///     .highlight([(2, 0)], Color::Red)
+///     .window(1..3, 0..2)
+///     .render(&grid, &mut out)
+///     .unwrap();
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), "O#\nO.\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GridRenderer {
+    overlays: Vec<Overlay>,
+    ansi: bool,
+    window: Option<(Range<usize>, Range<usize>)>,
+}
+
+impl GridRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws `glyph` instead of the cell at each of the given `(row, col)` positions.
+    pub fn overlay(self, positions: impl IntoIterator<Item = (usize, usize)>, glyph: char) -> Self {
+        self.push_overlay(positions, Some(glyph), None)
+    }
+
+    /// Like [`GridRenderer::overlay`], but also colours the glyph when ANSI output is enabled.
+    pub fn overlay_colored(
+        self,
+        positions: impl IntoIterator<Item = (usize, usize)>,
+        glyph: char,
+        color: Color,
+    ) -> Self {
+        self.push_overlay(positions, Some(glyph), Some(color))
+    }
+
+    /// Colours the original cells at the given positions when ANSI output is enabled.
+    pub fn highlight(self, positions: impl IntoIterator<Item = (usize, usize)>, color: Color) -> Self {
+        self.push_overlay(positions, None, Some(color))
+    }
+
+    /// Enables or disables ANSI colour escapes.
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    /// Only renders the given rows and columns. Ranges are clamped to the grid.
+    pub fn window(mut self, rows: Range<usize>, cols: Range<usize>) -> Self {
+        self.window = Some((rows, cols));
+        self
+    }
+
+    fn push_overlay(
+        mut self,
+        positions: impl IntoIterator<Item = (usize, usize)>,
+        glyph: Option<char>,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            positions: positions.into_iter().collect(),
+            glyph,
+            color,
+        });
+        self
+    }
+
+    /// Writes the grid to `out`, one row per line.
+    pub fn render<T: Display, W: Write>(&self, grid: &[Vec<T>], out: &mut W) -> io::Result<()> {
+        let (rows, cols) = self.window.clone().unwrap_or((0..usize::MAX, 0..usize::MAX));
+
+        for (i, row) in grid.iter().enumerate().take(rows.end).skip(rows.start) {
+            for (j, cell) in row.iter().enumerate().take(cols.end).skip(cols.start) {
+                let mut glyph = None;
+                let mut color = None;
+                for o in self.overlays.iter().filter(|o| o.positions.contains(&(i, j))) {
+                    glyph = o.glyph.or(glyph);
+                    color = o.color.or(color);
+                }
+
+                match (color.filter(|_| self.ansi), glyph) {
+                    (Some(c), Some(g)) => write!(out, "\x1b[{}m{g}\x1b[0m", c.ansi_code())?,
+                    (Some(c), None) => write!(out, "\x1b[{}m{cell}\x1b[0m", c.ansi_code())?,
+                    (None, Some(g)) => write!(out, "{g}")?,
+                    (None, None) => write!(out, "{cell}")?,
+                }
+            }
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the grid into a `String`.
+    pub fn render_to_string<T: Display>(&self, grid: &[Vec<T>]) -> String {
+        let mut out = vec![];
+        self.render(grid, &mut out).expect("writing to a Vec cannot fail");
+        String::from_utf8(out).expect("rendered grid is valid UTF-8")
+    }
+}
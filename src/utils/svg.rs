@@ -0,0 +1,302 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+type Point = (f64, f64);
+
+#[derive(Debug, Clone)]
+enum Element {
+    Polygon { points: Vec<Point>, fill: String, stroke: String },
+    Line { from: Point, to: Point, stroke: String },
+    Marker { at: Point, fill: String },
+    Label { at: Point, text: String },
+}
+
+/// Builds an SVG drawing in puzzle coordinates.
+///
+/// The view box is fitted around everything drawn, so coordinates can be as large as the input
+/// needs. Stroke widths, marker sizes and label sizes are in output pixels regardless of scale.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::utils::svg::Svg;
+///
+/// let mut svg = Svg::new();
+/// svg.polygon_with_labels(&[(0.0, 0.0), (6.0, 0.0), (6.0, 5.0)], "#ddd", "black");
+/// svg.marker((3.0, 2.0), "red");
+///
+/// let text = svg.to_string();
+/// assert!(text.starts_with("<svg"));
+/// assert!(text.contains("<polygon points=\"0,0 6,0 6,5\""));
+/// assert!(text.contains(">2</text>"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Svg {
+    width_px: f64,
+    elements: Vec<Element>,
+}
+
+impl Default for Svg {
+    fn default() -> Self {
+        Svg {
+            width_px: 800.0,
+            elements: vec![],
+        }
+    }
+}
+
+impl Svg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rendered size of the longer side in pixels. The other side follows the aspect
+    /// ratio of the drawing.
+    pub fn width(mut self, width_px: f64) -> Self {
+        self.width_px = width_px;
+        self
+    }
+
+    pub fn polygon(&mut self, points: &[Point], fill: &str, stroke: &str) {
+        self.elements.push(Element::Polygon {
+            points: points.to_vec(),
+            fill: fill.to_owned(),
+            stroke: stroke.to_owned(),
+        });
+    }
+
+    /// Draws a polygon and labels every vertex with its index.
+    pub fn polygon_with_labels(&mut self, points: &[Point], fill: &str, stroke: &str) {
+        self.polygon(points, fill, stroke);
+        for (i, p) in points.iter().enumerate() {
+            self.marker(*p, stroke);
+            self.label(*p, &i.to_string());
+        }
+    }
+
+    pub fn line(&mut self, from: Point, to: Point, stroke: &str) {
+        self.elements.push(Element::Line {
+            from,
+            to,
+            stroke: stroke.to_owned(),
+        });
+    }
+
+    /// A small filled dot, e.g. an intersection.
+    pub fn marker(&mut self, at: Point, fill: &str) {
+        self.elements.push(Element::Marker {
+            at,
+            fill: fill.to_owned(),
+        });
+    }
+
+    /// Writes `text` next to `at`. Characters that mean something in XML are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::svg::Svg;
+    ///
+    /// let mut svg = Svg::new();
+    /// svg.label((0.0, 0.0), "a < b & \"c\"");
+    /// assert!(svg.to_string().contains(">a &lt; b &amp; &quot;c&quot;</text>"));
+    /// ```
+    pub fn label(&mut self, at: Point, text: &str) {
+        self.elements.push(Element::Label {
+            at,
+            text: text.to_owned(),
+        });
+    }
+
+    /// Draws the part of the ray `origin + t * direction` (t >= 0) that lies inside the square
+    /// window `[min, max]²`, with a marker at the origin if it is inside. Returns whether anything
+    /// was drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::svg::Svg;
+    ///
+    /// let mut svg = Svg::new();
+    /// assert!(svg.ray((19.0, 13.0), (-2.0, 1.0), 7.0, 27.0, "blue"));
+    /// assert!(!svg.ray((19.0, 13.0), (2.0, 1.0), 0.0, 10.0, "blue"));
+    /// ```
+    pub fn ray(&mut self, origin: Point, direction: Point, min: f64, max: f64, stroke: &str) -> bool {
+        let Some((t0, t1)) = clip_ray(origin, direction, min, max) else {
+            return false;
+        };
+        let at = |t: f64| (origin.0 + direction.0 * t, origin.1 + direction.1 * t);
+        self.line(at(t0), at(t1), stroke);
+        if t0 == 0.0 {
+            self.marker(origin, stroke);
+        }
+        true
+    }
+
+    /// Draws an axis-aligned box in isometric projection, spanning `min` to `max` in `(x, y, z)`.
+    /// Boxes are painted in call order, so draw from the back (low z, low x + y) forwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::svg::Svg;
+    ///
+    /// let mut svg = Svg::new();
+    /// svg.iso_box((0.0, 0.0, 0.0), (3.0, 1.0, 1.0), "#8ac");
+    /// assert_eq!(svg.to_string().matches("<polygon").count(), 3);
+    /// ```
+    pub fn iso_box(&mut self, min: (f64, f64, f64), max: (f64, f64, f64), fill: &str) {
+        let (x0, y0, z0) = min;
+        let (x1, y1, z1) = max;
+        let p = |x: f64, y: f64, z: f64| iso_project(x, y, z);
+
+        let top = [p(x0, y0, z1), p(x1, y0, z1), p(x1, y1, z1), p(x0, y1, z1)];
+        let front_x = [p(x0, y1, z0), p(x1, y1, z0), p(x1, y1, z1), p(x0, y1, z1)];
+        let front_y = [p(x1, y0, z0), p(x1, y1, z0), p(x1, y1, z1), p(x1, y0, z1)];
+
+        self.polygon(&front_x, fill, "black");
+        self.polygon(&front_y, fill, "black");
+        self.polygon(&top, "white", "black");
+    }
+
+    fn bounds(&self) -> (Point, Point) {
+        let points = self.elements.iter().flat_map(|e| match e {
+            Element::Polygon { points, .. } => points.clone(),
+            Element::Line { from, to, .. } => vec![*from, *to],
+            Element::Marker { at, .. } | Element::Label { at, .. } => vec![*at],
+        });
+
+        let mut min = (f64::INFINITY, f64::INFINITY);
+        let mut max = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for (x, y) in points {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+
+        if min.0 > max.0 {
+            return ((0.0, 0.0), (1.0, 1.0));
+        }
+        (min, max)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl std::fmt::Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+        let span = (max_x - min_x).max(max_y - min_y).max(f64::EPSILON);
+        // User units per output pixel, used to keep markers and labels a constant size.
+        let px = span / self.width_px;
+        let pad = 20.0 * px;
+        let (view_w, view_h) = (max_x - min_x + 2.0 * pad, max_y - min_y + 2.0 * pad);
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+            (view_w / px).round(),
+            (view_h / px).round(),
+            num(min_x - pad),
+            num(min_y - pad),
+            num(view_w),
+            num(view_h),
+        )?;
+
+        for e in &self.elements {
+            // Colours go into attributes too, so they are escaped along with label text.
+            match e {
+                Element::Polygon { points, fill, stroke } => {
+                    let (fill, stroke) = (escape(fill), escape(stroke));
+                    let points = points.iter().map(|(x, y)| format!("{},{}", num(*x), num(*y))).collect::<Vec<_>>().join(" ");
+                    writeln!(
+                        out,
+                        "<polygon points=\"{points}\" fill=\"{fill}\" stroke=\"{stroke}\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+                    )?;
+                }
+                Element::Line { from, to, stroke } => writeln!(
+                    out,
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+                    num(from.0),
+                    num(from.1),
+                    num(to.0),
+                    num(to.1),
+                    escape(stroke),
+                )?,
+                Element::Marker { at, fill } => writeln!(
+                    out,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>",
+                    num(at.0),
+                    num(at.1),
+                    num(3.0 * px),
+                    escape(fill),
+                )?,
+                Element::Label { at, text } => writeln!(
+                    out,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"monospace\">{}</text>",
+                    num(at.0 + 4.0 * px),
+                    num(at.1 - 4.0 * px),
+                    num(12.0 * px),
+                    escape(text),
+                )?,
+            }
+        }
+
+        writeln!(out, "</svg>")?;
+        f.write_str(&out)
+    }
+}
+
+/// Escapes `text` for use in element content or a double-quoted attribute.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Rounds away floating point noise (and negative zero) before printing.
+fn num(v: f64) -> f64 {
+    let rounded = (v * 1e6).round() / 1e6;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+/// Projects a 3D point onto the page, with z pointing up.
+fn iso_project(x: f64, y: f64, z: f64) -> Point {
+    let (cos, sin) = (30f64.to_radians().cos(), 30f64.to_radians().sin());
+    ((x - y) * cos, (x + y) * sin - z)
+}
+
+/// Clips `origin + t * direction`, t >= 0, to the window (Liang–Barsky). Returns the `t` range.
+fn clip_ray(origin: Point, direction: Point, min: f64, max: f64) -> Option<(f64, f64)> {
+    let (mut t0, mut t1) = (0.0f64, f64::INFINITY);
+
+    for (o, d) in [(origin.0, direction.0), (origin.1, direction.1)] {
+        if d == 0.0 {
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let (a, b) = ((min - o) / d, (max - o) / d);
+        t0 = t0.max(a.min(b));
+        t1 = t1.min(a.max(b));
+    }
+
+    (t0 <= t1 && t1.is_finite()).then_some((t0, t1))
+}
@@ -0,0 +1,4 @@
+//! Advent of Code 2023.
+
+pub mod days;
+pub mod generate;
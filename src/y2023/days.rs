@@ -0,0 +1,131 @@
+//! The 2023 solvers for every day, each with a slow but obviously correct reference to check it by.
+//!
+//! Every `puzzleN` module has `part1` and `part2` (day 25 only has `part1`) and matching
+//! `reference_part1` and `reference_part2` functions. The references avoid the tricks the real
+//! solvers rely on (memoization, range splitting, extrapolation, LCMs) and are only fast enough
+//! for the small inputs from [`generate`](super::generate). [`differential`] runs both on those
+//! inputs.
+//!
+//! Some solvers only work because the real inputs are shaped a certain way. Those days declare
+//! the shape they rely on as [`Assumption`]s, which [`check`] verifies against a given input.
+//!
+//! [`Assumption`]: crate::runner::Assumption
+//! [`differential`]: crate::runner::differential
+//! [`check`]: crate::runner::check
+
+pub mod puzzle1;
+pub mod puzzle10;
+pub mod puzzle11;
+pub mod puzzle12;
+pub mod puzzle13;
+pub mod puzzle14;
+pub mod puzzle15;
+pub mod puzzle16;
+pub mod puzzle17;
+pub mod puzzle18;
+pub mod puzzle19;
+pub mod puzzle2;
+pub mod puzzle20;
+pub mod puzzle21;
+pub mod puzzle22;
+pub mod puzzle23;
+pub mod puzzle24;
+pub mod puzzle25;
+pub mod puzzle3;
+pub mod puzzle4;
+pub mod puzzle5;
+pub mod puzzle6;
+pub mod puzzle7;
+pub mod puzzle8;
+pub mod puzzle9;
+
+use crate::runner::{Day, Part};
+
+macro_rules! part {
+    ($day:ident::$solve:ident, $reference:ident) => {
+        Part {
+            solve: |input| $day::$solve(input).to_string(),
+            reference: Some(|input| $day::$reference(input).to_string()),
+            until: None,
+        }
+    };
+    ($day:ident::$solve:ident, $reference:ident, $until:ident) => {
+        Part {
+            until: Some(|input, cancel| {
+                $day::$until(input, cancel)
+                    .map(|answer| answer.to_string())
+                    .map_err(|cancelled| cancelled.map(|partial| partial.to_string()))
+            }),
+            ..part!($day::$solve, $reference)
+        }
+    };
+}
+
+macro_rules! day {
+    ($day:ident, $max_size:expr) => {
+        day!($day, $max_size, &[])
+    };
+    ($day:ident, $max_size:expr, $assumptions:expr) => {
+        Day {
+            part1: part!($day::part1, reference_part1),
+            part2: Some(part!($day::part2, reference_part2)),
+            max_size: $max_size,
+            assumptions: $assumptions,
+        }
+    };
+}
+
+/// The solvers for `day` (1-based), or `None` for days outside 1..=25.
+pub fn day(day: usize) -> Option<Day> {
+    Some(match day {
+        1 => day!(puzzle1, 8, puzzle1::ASSUMPTIONS),
+        2 => day!(puzzle2, 8),
+        3 => day!(puzzle3, 12),
+        4 => day!(puzzle4, 10),
+        5 => day!(puzzle5, 3),
+        6 => day!(puzzle6, 3),
+        7 => day!(puzzle7, 12),
+        8 => day!(puzzle8, 1, puzzle8::ASSUMPTIONS),
+        9 => day!(puzzle9, 8),
+        10 => day!(puzzle10, 9, puzzle10::ASSUMPTIONS),
+        11 => day!(puzzle11, 10),
+        12 => day!(puzzle12, 6),
+        13 => day!(puzzle13, 4),
+        14 => day!(puzzle14, 8),
+        15 => day!(puzzle15, 30),
+        16 => day!(puzzle16, 8),
+        17 => day!(puzzle17, 8),
+        18 => day!(puzzle18, 4),
+        19 => day!(puzzle19, 8),
+        20 => Day {
+            part2: Some(part!(puzzle20::part2, reference_part2, part2_until)),
+            ..day!(puzzle20, 1, puzzle20::ASSUMPTIONS)
+        },
+        // 26501365 steps are out of reach for a BFS, so `tests/puzzle21.rs` checks part 2 on
+        // shorter walks instead.
+        21 => Day {
+            part1: part!(puzzle21::part1, reference_part1),
+            part2: Some(Part {
+                solve: |input| puzzle21::part2(input).to_string(),
+                reference: None,
+                until: None,
+            }),
+            max_size: 11,
+            assumptions: puzzle21::ASSUMPTIONS,
+        },
+        22 => day!(puzzle22, 10),
+        23 => Day {
+            part1: part!(puzzle23::part1, reference_part1, part1_until),
+            part2: Some(part!(puzzle23::part2, reference_part2, part2_until)),
+            ..day!(puzzle23, 13)
+        },
+        24 => day!(puzzle24, 5),
+        25 => Day {
+            part1: part!(puzzle25::part1, reference_part1),
+            part2: None,
+            max_size: 18,
+            assumptions: &[],
+        },
+        _ => return None,
+    })
+}
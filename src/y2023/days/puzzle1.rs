@@ -0,0 +1,346 @@
+use std::fmt::{Display, Formatter};
+
+use crate::runner::Assumption;
+use crate::utils::automaton::Automaton;
+use crate::utils::parse::ParseError;
+
+/// The spelled out digits of part 2.
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The tokens that count as digits in a calibration line, and what each is worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Just the digits `0` to `9`, as in part 1.
+    pub fn digits() -> Self {
+        Self {
+            tokens: (0..10).map(|d| (d.to_string(), d)).collect(),
+        }
+    }
+
+    /// The digits and the English words for one to nine, as in part 2.
+    pub fn english() -> Self {
+        Self::digits().with_words(&ENGLISH)
+    }
+
+    /// Adds `words` to the vocabulary, replacing the value of any that are already in it.
+    /// Values above 9 are written out in full when a calibration value is put together, which is
+    /// an error if the result doesn't fit in a `u32`.
+    pub fn with_words(mut self, words: &[(&str, u32)]) -> Self {
+        for &(word, value) in words {
+            match self.tokens.iter_mut().find(|(w, _)| w == word) {
+                Some(token) => token.1 = value,
+                None => self.tokens.push((word.to_owned(), value)),
+            }
+        }
+        self
+    }
+}
+
+/// A token found in a calibration line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub value: u32,
+    /// The byte offset of the token in its line.
+    pub start: usize,
+}
+
+/// How one calibration line was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    /// The 1-based line number.
+    pub line: usize,
+    pub first: Token<'a>,
+    pub last: Token<'a>,
+    pub value: u32,
+}
+
+impl Display for Calibration<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` at {} and `{}` at {} make {}",
+            self.first.text, self.first.start, self.last.text, self.last.start, self.value
+        )
+    }
+}
+
+/// What to do with a line that has no tokens at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Digitless {
+    /// Fail with an error naming the line.
+    Error,
+    /// Leave the line out, as if it had a calibration value of 0.
+    Skip,
+}
+
+/// A line that the part 1 and part 2 vocabularies read differently, with how each read it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Difference<'a> {
+    /// The 1-based line number.
+    pub line: usize,
+    pub text: &'a str,
+    pub part1: Option<Calibration<'a>>,
+    pub part2: Option<Calibration<'a>>,
+}
+
+impl Display for Difference<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reading = |c: &Option<Calibration>| c.map_or("no digits".to_owned(), |c| c.to_string());
+        write!(
+            f,
+            "line {} `{}`: part 1 reads {}, part 2 reads {}",
+            self.line,
+            self.text,
+            reading(&self.part1),
+            reading(&self.part2)
+        )
+    }
+}
+
+/// Finds the tokens of a [`Vocabulary`] in calibration lines.
+///
+/// Tokens may overlap, and all of them count: in `eightwo` both `eight` and `two` are found, so
+/// `two` is the last token rather than being swallowed by `eight`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle1::{Calibrator, Vocabulary};
+///
+/// let english = Calibrator::new(&Vocabulary::english());
+/// let (first, last) = english.first_last("xeightwo").unwrap();
+/// assert_eq!((first.text, first.start, last.text, last.start), ("eight", 1, "two", 5));
+/// assert_eq!(english.value("xeightwo"), Ok(Some(82)));
+///
+/// let german = Calibrator::new(&Vocabulary::digits().with_words(&[("eins", 1), ("zwei", 2), ("zehn", 10)]));
+/// assert_eq!(german.value("zweinsx"), Ok(Some(21)));
+/// assert_eq!(german.value("3zehn"), Ok(Some(310)));
+/// assert_eq!(german.value("drei"), Ok(None));
+///
+/// let huge = Calibrator::new(&Vocabulary::digits().with_words(&[("lots", 4_000_000_000)]));
+/// let err = huge.value("lots1").unwrap_err();
+/// assert_eq!(err.to_string(), "line 1: `lots` and `1` make more than 4294967295: `lots1`");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Calibrator {
+    tokens: Vec<(String, u32)>,
+    automaton: Automaton,
+}
+
+impl Calibrator {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        Self {
+            automaton: Automaton::new(vocabulary.tokens.iter().map(|(w, _)| w)),
+            tokens: vocabulary.tokens.clone(),
+        }
+    }
+
+    /// Every token in `line`, ordered by where they start.
+    pub fn tokens<'a>(&self, line: &'a str) -> Vec<Token<'a>> {
+        let mut tokens = self
+            .automaton
+            .find_overlapping(line)
+            .map(|m| self.token(line, m.pattern, m.start))
+            .collect::<Vec<_>>();
+        tokens.sort_by_key(|t| t.start);
+        tokens
+    }
+
+    /// The first and last tokens in `line`, found in a single pass over it, or `None` if it has
+    /// none. They are the same token if there is only one.
+    pub fn first_last<'a>(&self, line: &'a str) -> Option<(Token<'a>, Token<'a>)> {
+        let mut matches = self.automaton.find_overlapping(line);
+        let m = matches.next()?;
+        let (mut first, mut last) = (m, m);
+        for m in matches {
+            if m.start < first.start {
+                first = m;
+            }
+            if m.start > last.start {
+                last = m;
+            }
+        }
+        Some((self.token(line, first.pattern, first.start), self.token(line, last.pattern, last.start)))
+    }
+
+    /// The calibration value of `line`: its first and last tokens written one after the other.
+    pub fn value(&self, line: &str) -> Result<Option<u32>, ParseError> {
+        Ok(self.read(1, line)?.map(|c| c.value))
+    }
+
+    /// How line number `number` of the input, `line`, is read, or `None` if it has no tokens.
+    /// Fails if the calibration value doesn't fit in a `u32`.
+    pub fn read<'a>(&self, number: usize, line: &'a str) -> Result<Option<Calibration<'a>>, ParseError> {
+        let Some((first, last)) = self.first_last(line) else {
+            return Ok(None);
+        };
+        let value = 10u32
+            .checked_pow(last.value.checked_ilog10().unwrap_or(0) + 1)
+            .and_then(|shift| first.value.checked_mul(shift))
+            .and_then(|high| high.checked_add(last.value))
+            .ok_or_else(|| {
+                let message = format!("`{}` and `{}` make more than {}", first.text, last.text, u32::MAX);
+                ParseError::new(line, message).at_line(number)
+            })?;
+        Ok(Some(Calibration {
+            line: number,
+            first,
+            last,
+            value,
+        }))
+    }
+
+    /// Reads every line of `input`, dealing with lines without tokens as `digitless` says.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle1::{Calibrator, Digitless, Vocabulary};
+    ///
+    /// let calibrator = Calibrator::new(&Vocabulary::digits());
+    /// let read = calibrator.calibrate("a1b2\nnone\n7", Digitless::Skip).unwrap();
+    /// assert_eq!(read.iter().map(|c| (c.line, c.value)).collect::<Vec<_>>(), [(1, 12), (3, 77)]);
+    /// assert_eq!(read[0].to_string(), "`1` at 1 and `2` at 3 make 12");
+    ///
+    /// let err = calibrator.calibrate("a1b2\nnone\n7", Digitless::Error).unwrap_err();
+    /// assert_eq!(err.to_string(), "line 2: no digits: `none`");
+    /// ```
+    pub fn calibrate<'a>(&self, input: &'a str, digitless: Digitless) -> Result<Vec<Calibration<'a>>, ParseError> {
+        let mut read = vec![];
+        for (i, line) in input.lines().enumerate() {
+            match (self.read(i + 1, line)?, digitless) {
+                (Some(calibration), _) => read.push(calibration),
+                (None, Digitless::Skip) => {}
+                (None, Digitless::Error) => return Err(ParseError::new(line, "no digits").at_line(i + 1)),
+            }
+        }
+        Ok(read)
+    }
+
+    fn token<'a>(&self, line: &'a str, pattern: usize, start: usize) -> Token<'a> {
+        let (word, value) = &self.tokens[pattern];
+        Token {
+            text: &line[start..start + word.len()],
+            value: *value,
+            start,
+        }
+    }
+}
+
+/// Both parts read every line as a calibration value, so a line without one is an error.
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        part: 1,
+        description: "every line has a digit",
+        check: |input| check_every_line(input, &Vocabulary::digits()),
+    },
+    Assumption {
+        part: 2,
+        description: "every line has a digit or a spelled out digit",
+        check: |input| check_every_line(input, &Vocabulary::english()),
+    },
+];
+
+fn check_every_line(input: &str, vocabulary: &Vocabulary) -> Result<(), String> {
+    match Calibrator::new(vocabulary).calibrate(input, Digitless::Error) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// The sum of the calibration values of every line of `input`, failing if it doesn't fit in a
+/// `u32`.
+pub fn calibration_sum(input: &str, vocabulary: &Vocabulary, digitless: Digitless) -> Result<u32, ParseError> {
+    let read = Calibrator::new(vocabulary).calibrate(input, digitless)?;
+    read.iter().try_fold(0u32, |sum, c| {
+        sum.checked_add(c.value).ok_or_else(|| {
+            let line = input.lines().nth(c.line - 1).unwrap_or_default();
+            ParseError::new(line, format!("calibration values sum to more than {}", u32::MAX)).at_line(c.line)
+        })
+    })
+}
+
+pub fn part1(input: &str) -> u32 {
+    calibration_sum(input, &Vocabulary::digits(), Digitless::Error).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn part2(input: &str) -> u32 {
+    calibration_sum(input, &Vocabulary::english(), Digitless::Error).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// The lines of `input` where part 2 picks a different first or last token than part 1.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle1::differences;
+///
+/// let report = differences("1abc2\ntwo1nine\nxyz\n");
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report[0].to_string(), "line 2 `two1nine`: part 1 reads `1` at 3 and `1` at 3 make 11, part 2 reads `two` at 0 and `nine` at 4 make 29");
+/// ```
+pub fn differences(input: &str) -> Vec<Difference<'_>> {
+    let (digits, english) = (Calibrator::new(&Vocabulary::digits()), Calibrator::new(&Vocabulary::english()));
+    // Neither vocabulary goes above 9, so no line reads as more than 99.
+    let read = |calibrator: &Calibrator, i: usize, text| calibrator.read(i + 1, text).unwrap_or_else(|e| panic!("{e}"));
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Difference {
+            line: i + 1,
+            text,
+            part1: read(&digits, i, text),
+            part2: read(&english, i, text),
+        })
+        .filter(|d| d.part1.map(|c| (c.first, c.last)) != d.part2.map(|c| (c.first, c.last)))
+        .collect()
+}
+
+/// The digit starting at byte `i` of `line`, if any, optionally accepting spelled out digits.
+fn digit_at(line: &str, i: usize, words: bool) -> Option<u32> {
+    const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    let rest = &line[i..];
+    if let Some(d) = rest.chars().next().and_then(|c| c.to_digit(10)) {
+        return Some(d);
+    }
+    if words {
+        return WORDS.iter().position(|w| rest.starts_with(w)).map(|p| p as u32 + 1);
+    }
+    None
+}
+
+fn reference_calibration(input: &str, words: bool) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits: Vec<u32> = (0..line.len()).filter_map(|i| digit_at(line, i, words)).collect();
+            digits[0] * 10 + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+/// Reference for [`part1`], checking every position of every line.
+pub fn reference_part1(input: &str) -> u32 {
+    reference_calibration(input, false)
+}
+
+/// Reference for [`part2`], checking every position of every line.
+pub fn reference_part2(input: &str) -> u32 {
+    reference_calibration(input, true)
+}
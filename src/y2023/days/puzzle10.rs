@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+use crate::runner::Assumption;
+use crate::utils::{iter_neighbors4, iter_neighbors4_bounded};
+
+type Pos = (i64, i64);
+
+const NORTH: Pos = (-1i64, 0i64);
+const SOUTH: Pos = (1, 0);
+const WEST: Pos = (0, -1);
+const EAST: Pos = (0, 1);
+
+/// Part 2 flood fills the tiles on the right of the loop and counts the rest as enclosed.
+pub const ASSUMPTIONS: &[Assumption] = &[Assumption {
+    part: 2,
+    description: "the loop is followed anticlockwise, so the flood fill marks the outside",
+    check: check_orientation,
+}];
+
+fn parse(input: &str) -> (Vec<Vec<char>>, Pos) {
+    let grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+
+    let starting_position = grid.iter().enumerate().map(|(i, row)| {
+        let s_idx = row.iter().position(|c| c == &'S');
+        (i, s_idx)
+    })
+        .filter(|(_, s_opt)| s_opt.is_some())
+        .map(|(i, s_opt)| (i as i64, s_opt.unwrap() as i64))
+        .next_back().unwrap();
+
+    (grid, starting_position)
+}
+
+/// The top left tile of the loop is always an `F`, with the outside above and to the left of it.
+/// The right hand side of the walk is the outside when the walk goes down from there.
+fn check_orientation(input: &str) -> Result<(), String> {
+    let (grid, starting_position) = parse(input);
+    let (_, pipes) = dfs_queue(&grid, starting_position, grid.len() as i64, grid[0].len() as i64);
+
+    let walk = pipes.iter().rev().collect_vec();
+    let (corner, &top_left) = walk.iter().enumerate().min_by_key(|(_, p)| **p).unwrap();
+    let next = walk[(corner + 1) % walk.len()];
+    if *next != (top_left.0 + 1, top_left.1) {
+        return Err(format!("it is followed clockwise, going right from {top_left:?}"));
+    }
+    Ok(())
+}
+
+/// The farthest distance along the loop and the number of tiles it encloses.
+fn scan(input: &str) -> (i64, usize) {
+    let (grid, starting_position) = parse(input);
+
+    let max_x = grid.len() as i64;
+    let max_y = grid[0].len() as i64;
+
+    let mut path: HashSet<Pos> = HashSet::new();
+    path.insert(starting_position);
+
+    let (dist, pipes) = dfs_queue(&grid, starting_position, max_x, max_y);
+
+    let mut grid2 = vec![];
+
+    for _ in 0..max_x {
+        grid2.push(vec!['*'; max_y as usize]);
+    }
+
+    for p in &pipes {
+        grid2[p.0 as usize][p.1 as usize] = 'P';
+    }
+
+    let pipe_set: HashSet<Pos> = pipes.iter().copied().collect();
+
+
+    let ptm = find_points_to_mark(starting_position, &pipes);
+
+    for point in ptm {
+        mark(&mut grid2, point, &pipe_set);
+    }
+
+    let count: usize = grid2.iter().map(|row| row.iter().filter(|c| c == &&'X').count()).sum();
+
+    // By visual inspection -- flood fill marked the outer points.
+    // So inner points = total points - pipe path points - outer points.
+    let inner_point_count = (max_x * max_y) as usize - count - pipes.len();
+    (dist / 2, inner_point_count)
+}
+
+pub fn part1(input: &str) -> i64 {
+    scan(input).0
+}
+
+pub fn part2(input: &str) -> usize {
+    scan(input).1
+}
+
+/// The directions a pipe connects to.
+fn openings(c: char) -> &'static [Pos] {
+    match c {
+        '|' => &[NORTH, SOUTH],
+        '-' => &[WEST, EAST],
+        'L' => &[NORTH, EAST],
+        'J' => &[NORTH, WEST],
+        '7' => &[SOUTH, WEST],
+        'F' => &[SOUTH, EAST],
+        _ => &[],
+    }
+}
+
+/// The loop through `S` in walking order, with `S` replaced by the pipe it stands for.
+fn reference_loop(input: &str) -> (Vec<Vec<char>>, Vec<Pos>) {
+    let mut grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+    let at = |grid: &Vec<Vec<char>>, (x, y): Pos| {
+        grid.get(x as usize).and_then(|row| row.get(y as usize)).copied().unwrap_or('.')
+    };
+
+    let start = (0..grid.len())
+        .flat_map(|x| (0..grid[x].len()).map(move |y| (x as i64, y as i64)))
+        .find(|&p| at(&grid, p) == 'S')
+        .unwrap();
+
+    let connected = [NORTH, SOUTH, WEST, EAST]
+        .into_iter()
+        .filter(|&(dx, dy)| {
+            let next = (start.0 + dx, start.1 + dy);
+            openings(at(&grid, next)).contains(&(-dx, -dy))
+        })
+        .collect_vec();
+    let shape = "|-LJ7F".chars().find(|&c| openings(c) == connected.as_slice()).unwrap();
+    grid[start.0 as usize][start.1 as usize] = shape;
+
+    let mut path = vec![start];
+    let mut from = (0, 0);
+    let mut current = start;
+    loop {
+        let &(dx, dy) = openings(at(&grid, current)).iter().find(|&&d| d != from).unwrap();
+        current = (current.0 + dx, current.1 + dy);
+        from = (-dx, -dy);
+        if current == start {
+            break;
+        }
+        path.push(current);
+    }
+
+    (grid, path)
+}
+
+/// Reference for [`part1`], walking the whole loop and halving its length.
+pub fn reference_part1(input: &str) -> i64 {
+    reference_loop(input).1.len() as i64 / 2
+}
+
+/// Reference for [`part2`], casting a ray to the left of every tile and counting how many
+/// north-facing loop pipes it crosses.
+pub fn reference_part2(input: &str) -> usize {
+    let (grid, path) = reference_loop(input);
+    let on_loop: HashSet<Pos> = path.into_iter().collect();
+
+    let mut inside = 0;
+    for (x, row) in grid.iter().enumerate() {
+        let mut crossings = 0;
+        for (y, &c) in row.iter().enumerate() {
+            if on_loop.contains(&(x as i64, y as i64)) {
+                if openings(c).contains(&NORTH) {
+                    crossings += 1;
+                }
+            } else if crossings % 2 == 1 {
+                inside += 1;
+            }
+        }
+    }
+    inside
+}
+
+fn find_points_to_mark(starting_position: Pos, pipes: &[Pos]) -> Vec<Pos> {
+    let mut prev = starting_position;
+    let mut points_to_mark = vec![];
+
+    for i in (0..pipes.len() - 1).rev() {
+        let curr = pipes[i];
+        // Algorithm from Reddit - Mark only the points on the right side of the loop while
+        // following the path.
+        match (curr.0 - prev.0, curr.1 - prev.1) {
+            (1, 0) => {
+                // x│
+                // x↓
+                points_to_mark.push((curr.0, curr.1 - 1));
+                points_to_mark.push((curr.0 - 1, curr.1 - 1));
+            }
+            (0, 1) => {
+                // -→
+                // xx
+                points_to_mark.push((curr.0 + 1, curr.1));
+                points_to_mark.push((curr.0 + 1, curr.1 - 1));
+            }
+            (-1, 0) => {
+                // ↑x
+                // │x
+                points_to_mark.push((curr.0, curr.1 + 1));
+                points_to_mark.push((curr.0 + 1, curr.1 + 1));
+            }
+            (0, -1) => {
+                // xx
+                // ←-
+                points_to_mark.push((curr.0 - 1, curr.1));
+                points_to_mark.push((curr.0 - 1, curr.1 + 1));
+            }
+            (_, _) => {}
+        }
+        prev = curr;
+    }
+
+    points_to_mark
+}
+
+fn mark(grid: &mut Vec<Vec<char>>, current: Pos, pipes: &HashSet<Pos>) {
+    if current.0 < 0 || current.0 >= grid.len() as i64 || current.1 < 0 || current.1 >= grid[0].len() as i64 {
+        return;
+    }
+
+    if pipes.contains(&current) {
+        return;
+    }
+
+    if grid[current.0 as usize][current.1 as usize] == 'X' {
+        return;
+    }
+
+    grid[current.0 as usize][current.1 as usize] = 'X';
+
+    for next in iter_neighbors4(current.0, current.1) {
+        mark(grid, next, pipes);
+    }
+}
+
+// todo: Handle cycles
+fn dfs_queue(grid: &[Vec<char>], starting_position: Pos, max_x: i64, max_y: i64) -> (i64, Vec<Pos>) {
+    let mut queue: VecDeque<(Pos, Pos, i64)> = VecDeque::new();
+
+    let mut max_dist_so_far = i64::MIN;
+    queue.push_front((starting_position, starting_position, 0));
+
+    let mut parent_map: HashMap<Pos, Pos> = HashMap::new();
+    let mut target = (0, 0);
+
+    while let Some((current, previous, distance)) = queue.pop_front() {
+        for neighbor in iter_neighbors4_bounded(current.0, current.1, max_x, max_y) {
+            let curr = grid[current.0 as usize][current.1 as usize];
+            let next = grid[neighbor.0 as usize][neighbor.1 as usize];
+            if neighbor != previous
+                && !queue.iter().any(|st| st.0 == neighbor)
+                && is_compatible(curr, next, (neighbor.0 - current.0, neighbor.1 - current.1)) {
+                if next == 'S' {
+                    max_dist_so_far = max_dist_so_far.max(distance + 1);
+                    target = current;
+                    break;
+                }
+                // println!("Current: {current:?}: {curr}. Next: {neighbor:?}: {next}");
+                parent_map.insert(neighbor, current);
+                queue.push_front((neighbor, current, distance + 1));
+            }
+        }
+    }
+
+    let mut curr = target;
+    let mut pipes = vec![target];
+    while let Some(next) = parent_map.get(&curr) {
+        curr = *next;
+        pipes.push(curr);
+    }
+
+
+    (max_dist_so_far, pipes)
+}
+
+fn is_compatible(current: char, next: char, direction: Pos) -> bool {
+    match (current, direction, next) {
+        (_, _, 'S') => true,
+        ('S' | '|' | 'L' | 'J', NORTH, '|' | '7' | 'F') => true,
+        ('S' | '|' | '7' | 'F', SOUTH, '|' | 'L' | 'J') => true,
+        ('S' | '-' | 'L' | 'F', EAST, '-' | 'J' | '7') => true,
+        ('S' | '-' | 'J' | '7', WEST, '-' | 'L' | 'F') => true,
+        (_, _, _) => false
+    }
+}
\ No newline at end of file
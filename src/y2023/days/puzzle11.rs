@@ -0,0 +1,229 @@
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use crate::utils::iter_neighbors4_bounded;
+
+type Pos = (i64, i64);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Node {
+    position: Pos,
+    cost: usize,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// todo: Reimplement this using simpler Manhattan Distance
+fn scan(input: &str) -> (usize, usize) {
+    let grid = input.lines().map(|s| s.chars().collect_vec()).collect_vec();
+
+    let mut grid_part1 = grid.clone();
+
+    let orig_y = grid_part1[0].len();
+
+    let rows_to_expand = grid_part1
+        .iter()
+        .positions(|row| row.iter().all(|c| c != &'#'))
+        .collect_vec();
+
+    let cols_to_expand = (0..orig_y)
+        .filter(|&j| grid_part1.iter().all(|row| row[j] != '#'))
+        .collect_vec();
+
+    insert_rows(&mut grid_part1, &rows_to_expand);
+    insert_cols(&mut grid_part1, &cols_to_expand);
+
+    let galaxies: Vec<Pos> = find_galaxies(&grid_part1);
+
+    let mut pair_distances: HashMap<(Pos, Pos), usize> = HashMap::new();
+
+    for (i, &source) in galaxies.iter().enumerate() {
+        let (distances, _) = find_shortest_path_bfs(&grid_part1, source);
+        for &target in &galaxies[i + 1..] {
+            pair_distances.insert((source, target), *distances.get(&target).unwrap());
+            pair_distances.insert((target, source), *distances.get(&target).unwrap());
+        }
+    }
+
+    let sum: usize = pair_distances.values().sum();
+    let part1 = sum / 2;
+
+    let grid_part2 = grid.clone();
+
+    let galaxies: Vec<Pos> = find_galaxies(&grid_part2);
+
+    let expanded_rows_set: HashSet<usize> = rows_to_expand.iter().copied().collect();
+    let expanded_cols_set: HashSet<usize> = cols_to_expand.iter().copied().collect();
+
+    let mut pair_distances: HashMap<(Pos, Pos), usize> = HashMap::new();
+    for (i, &source) in galaxies.iter().enumerate() {
+        let (distances, paths) = find_shortest_path_bfs(&grid_part2, source);
+        for &target in &galaxies[i + 1..] {
+
+            let original_distance = *distances.get(&target).unwrap();
+            let original_path = find_path(&paths, target);
+
+            let expanded_distance = expand_distance(original_distance, &original_path, &expanded_rows_set, &expanded_cols_set);
+
+            pair_distances.insert((source, target), expanded_distance);
+            pair_distances.insert((target, source), expanded_distance);
+        }
+    }
+
+    let sum: usize = pair_distances.values().sum();
+    (part1, sum / 2)
+}
+
+pub fn part1(input: &str) -> usize {
+    scan(input).0
+}
+
+pub fn part2(input: &str) -> usize {
+    scan(input).1
+}
+
+/// Sums the distances between every pair of galaxies, walking from one to the other a row or
+/// column at a time and counting each empty row or column crossed as `factor` of them.
+fn reference_distances(input: &str, factor: usize) -> usize {
+    let grid = input.lines().map(|s| s.chars().collect_vec()).collect_vec();
+    let empty_row = |x: usize| grid[x].iter().all(|&c| c != '#');
+    let empty_col = |y: usize| grid.iter().all(|row| row[y] != '#');
+    let galaxies = find_galaxies(&grid);
+
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            let (x1, x2) = (a.0.min(b.0) as usize, a.0.max(b.0) as usize);
+            let (y1, y2) = (a.1.min(b.1) as usize, a.1.max(b.1) as usize);
+            total += (x1 + 1..=x2).map(|x| if empty_row(x) { factor } else { 1 }).sum::<usize>();
+            total += (y1 + 1..=y2).map(|y| if empty_col(y) { factor } else { 1 }).sum::<usize>();
+        }
+    }
+    total
+}
+
+/// Reference for [`part1`], doubling every empty row and column.
+pub fn reference_part1(input: &str) -> usize {
+    reference_distances(input, 2)
+}
+
+/// Reference for [`part2`], replacing every empty row and column with a million.
+pub fn reference_part2(input: &str) -> usize {
+    reference_distances(input, 1_000_000)
+}
+
+fn expand_distance(distance: usize, path: &Vec<Pos>, expanded_rows: &HashSet<usize>, expanded_cols: &HashSet<usize>) -> usize {
+    let expansion_factor = 999_999usize;
+    // let expansion_factor = 9usize;
+    let mut total_expansion = 0;
+
+    for p in path {
+        if expanded_rows.contains(&(p.0 as usize)) || expanded_cols.contains(&(p.1 as usize)) {
+            total_expansion += expansion_factor;
+        }
+    }
+
+    for p in path {
+        if expanded_rows.contains(&(p.0 as usize)) && expanded_cols.contains(&(p.1 as usize)) {
+            total_expansion -= 1;
+        }
+    }
+
+    distance + total_expansion
+}
+
+fn find_path(paths: &HashMap<Pos, Pos>, target: Pos) -> Vec<Pos> {
+    let mut curr = target;
+    let mut path = vec![];
+
+    while let Some(next) = paths.get(&curr) {
+        path.push(curr);
+        curr = *next;
+    }
+
+    path
+}
+
+// or just use damn Manhattan distance :(
+fn find_shortest_path_bfs(grid: &[Vec<char>], source: Pos) -> (HashMap<Pos, usize>, HashMap<Pos, Pos>) {
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    let mut dist: HashMap<Pos, usize> = HashMap::new();
+    let mut prev: HashMap<Pos, Pos> = HashMap::new();
+    for i in 0..max_x {
+        for j in 0..max_y {
+            dist.insert((i as i64, j as i64), usize::MAX);
+        }
+    }
+
+    let mut heap = BinaryHeap::new();
+    dist.insert(source, 0);
+
+    heap.push(Node {
+        position: source,
+        cost: 0,
+    });
+
+    while let Some(Node { position, cost }) = heap.pop() {
+        if cost > *dist.get(&position).unwrap() {
+            continue;
+        }
+
+        for neighbor in iter_neighbors4_bounded(position.0, position.1, max_x as i64, max_y as i64) {
+            let next = Node { position: neighbor, cost: cost + 1 };
+
+            if next.cost < *dist.get(&neighbor).unwrap() {
+                heap.push(next);
+                prev.entry(neighbor).and_modify(|e| *e = position).or_insert(position);
+                dist.insert(next.position, next.cost);
+            }
+        }
+    }
+
+    (dist, prev)
+}
+
+fn find_galaxies(grid: &[Vec<char>]) -> Vec<Pos> {
+    let mut galaxies: Vec<Pos> = vec![];
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, c) in row.iter().enumerate() {
+            if c == &'#' {
+                galaxies.push((i as i64, j as i64));
+            }
+        }
+    }
+
+    galaxies
+}
+
+fn insert_cols(grid: &mut [Vec<char>], cols_to_expand: &[usize]) {
+    for c in cols_to_expand.iter().rev() {
+        for row in grid.iter_mut() {
+            row.insert(*c, '.');
+        }
+    }
+}
+
+fn insert_rows(grid: &mut Vec<Vec<char>>, rows_to_expand: &[usize]) {
+    let row_size = grid[0].len();
+    let row = vec!['.'; row_size];
+
+    for r in rows_to_expand.iter().rev() {
+        grid.insert(*r, row.clone());
+    }
+}
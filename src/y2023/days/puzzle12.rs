@@ -0,0 +1,147 @@
+use itertools::Itertools;
+use crate::utils::memo::Memo;
+
+type Key = (usize, usize, usize);
+
+fn parse(input: &str) -> Vec<(&str, Vec<usize>)> {
+    input
+        .lines()
+        .map(|l| {
+            let (springs, groups) = l.split_ascii_whitespace().collect_tuple().unwrap();
+            (
+                springs,
+                groups.split(',').map(|s| s.parse().unwrap()).collect_vec(),
+            )
+        })
+        .collect_vec()
+}
+
+pub fn part1(input: &str) -> usize {
+    let mut sum = 0;
+    for (s, group) in &parse(input) {
+        let chars = s.chars().collect_vec();
+        let mut mem = Memo::new();
+        let count = backtrack_memoized(&chars, 0, group, 0, 0, &mut mem);
+        sum += count;
+    }
+    sum
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut sum = 0;
+    for (s, group) in &parse(input) {
+        let chars = s.chars().collect_vec();
+        let cloned_chars = Itertools::intersperse([chars.clone(), chars.clone(), chars.clone(), chars.clone(), chars.clone()].iter(), &vec!['?']).flatten().copied().collect_vec();
+        let cloned_groups = group.repeat(5).iter().copied().collect_vec();
+        let mut mem: Memo<Key, usize> = Memo::new();
+        let count = backtrack_memoized(&cloned_chars, 0, &cloned_groups, 0, 0, &mut mem);
+        sum += count;
+    }
+    sum
+}
+
+/// The lengths of the runs of `#` in `springs`.
+fn runs(springs: &[char]) -> Vec<usize> {
+    springs
+        .split(|&c| c != '#')
+        .map(<[char]>::len)
+        .filter(|&n| n > 0)
+        .collect()
+}
+
+/// Reference for [`part1`], trying both values for every `?`.
+pub fn reference_part1(input: &str) -> usize {
+    parse(input)
+        .iter()
+        .map(|(springs, groups)| {
+            let unknown = springs.chars().positions(|c| c == '?').collect_vec();
+            (0u64..1 << unknown.len())
+                .filter(|mask| {
+                    let mut filled = springs.chars().collect_vec();
+                    for (bit, &i) in unknown.iter().enumerate() {
+                        filled[i] = if mask >> bit & 1 == 1 { '#' } else { '.' };
+                    }
+                    runs(&filled) == *groups
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Counts the matches of the pattern `.*#{g1}.+#{g2}.+ ... .*` by running it as an automaton
+/// over `springs` and tracking how many assignments reach each state.
+fn reference_matches(springs: &[char], groups: &[usize]) -> usize {
+    // One state per character of the canonical row `.#..#.` built from the groups, where the
+    // separating `.` states may repeat.
+    let mut pattern = vec!['.'];
+    for &g in groups {
+        pattern.extend(std::iter::repeat_n('#', g));
+        pattern.push('.');
+    }
+
+    let mut counts = vec![0usize; pattern.len()];
+    counts[0] = 1;
+    for &c in springs {
+        let mut next = vec![0; pattern.len()];
+        for (state, &count) in counts.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            for step in [state, state + 1] {
+                let repeats = step == state;
+                if step >= pattern.len() || (repeats && pattern[state] == '#') {
+                    continue;
+                }
+                if c == '?' || c == pattern[step] {
+                    next[step] += count;
+                }
+            }
+        }
+        counts = next;
+    }
+
+    counts[pattern.len() - 1] + counts[pattern.len() - 2]
+}
+
+/// Reference for [`part2`], running the unfolded rows through an automaton.
+pub fn reference_part2(input: &str) -> usize {
+    parse(input)
+        .iter()
+        .map(|(springs, groups)| {
+            let unfolded = [*springs; 5].join("?").chars().collect_vec();
+            reference_matches(&unfolded, &groups.repeat(5))
+        })
+        .sum()
+}
+
+
+fn backtrack_memoized(spring: &[char], current_pos: usize, groups: &[usize], current_group_pos: usize, current_group_size: usize, mem: &mut Memo<Key, usize>) -> usize {
+    mem.get_or_insert_with((current_pos, current_group_pos, current_group_size), |mem| {
+        if current_pos == spring.len() {
+            if current_group_pos == groups.len() && current_group_size == 0 {
+                return 1;
+            }
+
+            if current_group_pos == groups.len() - 1 && groups[current_group_pos] == current_group_size {
+                return 1;
+            }
+
+            return 0;
+        }
+
+        let mut answer = 0;
+        if spring[current_pos] == '.' || spring[current_pos] == '?' {
+            if current_group_size == 0 {
+                answer += backtrack_memoized(spring, current_pos + 1, groups, current_group_pos, 0, mem);
+            } else if current_group_size > 0 && current_group_pos < groups.len() && groups[current_group_pos] == current_group_size {
+                answer += backtrack_memoized(spring, current_pos + 1, groups, current_group_pos + 1, 0, mem);
+            }
+        }
+
+        if spring[current_pos] == '#' || spring[current_pos] == '?' {
+            answer += backtrack_memoized(spring, current_pos + 1, groups, current_group_pos, current_group_size + 1, mem);
+        }
+
+        answer
+    })
+}
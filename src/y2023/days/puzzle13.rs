@@ -0,0 +1,243 @@
+use std::cmp::Ordering::{Equal, Greater, Less};
+use itertools::Itertools;
+
+/// Sums the mirror lines of every pattern, without and with fixing the smudge.
+fn scan(input: &str) -> (usize, usize) {
+    let patterns = input.split("\n\n").collect_vec();
+
+    let mut col_sum = 0;
+    let mut row_sum = 0;
+
+    let mut smudge_col_sum = 0;
+    let mut smudge_row_sum = 0;
+
+    for p in &patterns {
+        let grid = p.lines().map(|l| l.chars().collect_vec()).collect_vec();
+
+        if let Some(c) = find_column_mirrored(&grid) {
+            col_sum += c + 1;
+        }
+
+        if let Some(c) = find_column_mirrored_with_smudge(&grid) {
+            smudge_col_sum += c + 1;
+        }
+
+        if let Some(r) = find_row_mirrored(&grid) {
+            row_sum += r + 1;
+        }
+
+        if let Some(r) = find_row_mirrored_with_smudge(&grid) {
+            smudge_row_sum += r + 1;
+        }
+    }
+
+    (col_sum + 100 * row_sum, smudge_col_sum + 100 * smudge_row_sum)
+}
+
+pub fn part1(input: &str) -> usize {
+    scan(input).0
+}
+
+pub fn part2(input: &str) -> usize {
+    scan(input).1
+}
+
+/// The summary value of every line `grid` is mirrored across, checking each cell directly.
+fn reference_reflections(grid: &[Vec<char>]) -> Vec<usize> {
+    let (rows, cols) = (grid.len(), grid[0].len());
+    let mirrored_at = |k: usize, len: usize, cell: &dyn Fn(usize, usize) -> char, other: usize| {
+        (0..k).all(|a| {
+            let b = 2 * k - 1 - a;
+            b >= len || (0..other).all(|o| cell(a, o) == cell(b, o))
+        })
+    };
+
+    let by_col = |a: usize, o: usize| grid[o][a];
+    let by_row = |a: usize, o: usize| grid[a][o];
+    let mut lines = (1..cols).filter(|&k| mirrored_at(k, cols, &by_col, rows)).collect_vec();
+    lines.extend((1..rows).filter(|&k| mirrored_at(k, rows, &by_row, cols)).map(|k| 100 * k));
+    lines
+}
+
+fn reference_patterns(input: &str) -> Vec<Vec<Vec<char>>> {
+    input
+        .split("\n\n")
+        .map(|p| p.lines().map(|l| l.chars().collect_vec()).collect_vec())
+        .collect()
+}
+
+/// Reference for [`part1`], testing every candidate line against every cell.
+pub fn reference_part1(input: &str) -> usize {
+    reference_patterns(input)
+        .iter()
+        .map(|grid| reference_reflections(grid).first().copied().unwrap_or(0))
+        .sum()
+}
+
+/// Reference for [`part2`], flipping every cell in turn and looking for a new mirror line.
+pub fn reference_part2(input: &str) -> usize {
+    reference_patterns(input)
+        .into_iter()
+        .map(|mut grid| {
+            let original = reference_reflections(&grid);
+            for x in 0..grid.len() {
+                for y in 0..grid[0].len() {
+                    let c = grid[x][y];
+                    grid[x][y] = if c == '#' { '.' } else { '#' };
+                    let new = reference_reflections(&grid).into_iter().find(|l| !original.contains(l));
+                    grid[x][y] = c;
+                    if let Some(line) = new {
+                        return line;
+                    }
+                }
+            }
+            0
+        })
+        .sum()
+}
+
+fn find_column_mirrored_with_smudge(grid: &[Vec<char>]) -> Option<usize> {
+    let cols = grid[0].len();
+
+    for j in 0..cols - 1 {
+        let mut l = j as isize;
+        let mut r = j + 1;
+        let mut nequal_count = 0;
+        let mut smudge_found = false;
+        while l >= 0 && r < cols {
+            let equal_with_smudge = cols_equal_with_smudge(grid, l as usize, r);
+            if !equal_with_smudge.0 {
+                nequal_count += 1;
+            }
+            if equal_with_smudge.1.is_some() {
+                smudge_found = true;
+            }
+            l -= 1;
+            r += 1;
+        }
+
+        if nequal_count == 1 && smudge_found {
+            return Some(j);
+        }
+    }
+
+    None
+}
+
+fn find_column_mirrored(grid: &[Vec<char>]) -> Option<usize> {
+    let cols = grid[0].len();
+
+    for j in 0..cols - 1 {
+        let mut l = j as isize;
+        let mut r = j + 1;
+        let mut mirrored = true;
+        'w: while l >= 0 && r < cols {
+            let equal_with_smudge = cols_equal_with_smudge(grid, l as usize, r);
+            if !equal_with_smudge.0 {
+                mirrored = false;
+                break 'w;
+            }
+            l -= 1;
+            r += 1;
+        }
+
+        if mirrored {
+            return Some(j);
+        }
+    }
+
+    None
+}
+
+fn find_row_mirrored_with_smudge(grid: &[Vec<char>]) -> Option<usize> {
+    let rows = grid.len();
+
+    for i in 0..rows - 1 {
+        let mut t = i as isize;
+        let mut b = i + 1;
+        let mut nequal_count = 0;
+        let mut smudge_found = false;
+        while t >= 0 && b < rows {
+            let equal_with_smudge = rows_equal_with_smudge(grid, t as usize, b);
+            if !equal_with_smudge.0 {
+                nequal_count += 1;
+            }
+
+            if equal_with_smudge.1.is_some() {
+                smudge_found = true;
+            }
+
+            t -= 1;
+            b += 1;
+        }
+
+        if nequal_count == 1 && smudge_found {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn find_row_mirrored(grid: &[Vec<char>]) -> Option<usize> {
+    let rows = grid.len();
+
+    for i in 0..rows - 1 {
+        let mut t = i as isize;
+        let mut b = i + 1;
+        let mut mirrored = true;
+        'w: while t >= 0 && b < rows {
+            let equal_with_smudge = rows_equal_with_smudge(grid, t as usize, b);
+            if !equal_with_smudge.0 {
+                mirrored = false;
+                break 'w;
+            }
+
+            t -= 1;
+            b += 1;
+        }
+
+        if mirrored {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn cols_equal_with_smudge(grid: &[Vec<char>], c1: usize, c2: usize) -> (bool, Option<usize>) {
+    let mut nequal_count = 0;
+    let mut nequal_index = 0;
+    for (i, row) in grid.iter().enumerate() {
+        if row[c1] != row[c2] {
+            nequal_count += 1;
+            nequal_index = i;
+        }
+    }
+
+    match nequal_count.cmp(&1) {
+        Equal => (false, Some(nequal_index)),
+        Greater => (false, None),
+        Less => (true, None)
+    }
+}
+
+fn rows_equal_with_smudge(grid: &[Vec<char>], r1: usize, r2: usize) -> (bool, Option<usize>) {
+    let row1 = &grid[r1];
+    let row2 = &grid[r2];
+    let mut nequal_count = 0;
+    let mut nequal_index = 0;
+
+    for ((i, a), b) in row1.iter().enumerate().zip(row2.iter()) {
+        if a != b {
+            nequal_count += 1;
+            nequal_index = i;
+        }
+    }
+
+    match nequal_count.cmp(&1) {
+        Equal => (false, Some(nequal_index)),
+        Greater => (false, None),
+        Less => (true, None)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,319 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+use itertools::Itertools;
+use crate::utils::anim::Animator;
+use crate::utils::render::GridRenderer;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Rounded,
+    Cube,
+    Empty,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '#' => Cell::Cube,
+            'O' => Cell::Rounded,
+            '.' => Cell::Empty,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Debug for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}",
+            match self {
+                Cell::Empty => ".",
+                Cell::Rounded => "O",
+                Cell::Cube => "#",
+            }
+        ))
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+type Pos = (usize, usize);
+
+pub fn part1(input: &str) -> usize {
+    let mut grid = input
+        .lines()
+        .map(|l| l.chars().map(Cell::from).collect_vec())
+        .collect_vec();
+
+    let mut rounded_rock_row_counts: HashMap<usize, usize> = HashMap::new();
+
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    for i in 0..max_x {
+        for j in 0..max_y {
+            if grid[i][j] == Cell::Rounded {
+                let final_row = roll_north_along_column(&mut grid, i, j);
+                rounded_rock_row_counts
+                    .entry(final_row)
+                    .and_modify(|e| *e += 1)
+                    .or_insert(1);
+            }
+        }
+    }
+
+    rounded_rock_row_counts
+        .iter()
+        .map(|(k, v)| v * (max_x - k))
+        .sum()
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut grid = input
+        .lines()
+        .map(|l| l.chars().map(Cell::from).collect_vec())
+        .collect_vec();
+
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    let mut state_set: HashMap<Vec<Pos>, usize> = HashMap::new();
+    let mut cycles = 0;
+
+    let cycle_count;
+
+    loop {
+        perform_cycle(&mut grid, max_x, max_y);
+        cycles += 1;
+
+        let state = get_grid_state(&grid);
+
+        if state_set.contains_key(&state) {
+            cycle_count = cycles - state_set.get(&state).unwrap();
+            break;
+        }
+
+        state_set.insert(state, cycles);
+    }
+    let remaining = (1_000_000_000 - cycles) % cycle_count;
+
+    for _ in 0..remaining {
+        perform_cycle(&mut grid, max_x, max_y);
+    }
+
+    grid
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c == Cell::Rounded)
+                .map(move |(j, _)| (i, j))
+        })
+        .counts_by(|p| p.0)
+        .iter()
+        .map(|(k, v)| v * (max_x - k))
+        .sum()
+}
+
+pub fn animate_cycles(input: &str) {
+    let mut grid = input
+        .lines()
+        .map(|l| l.chars().map(Cell::from).collect_vec())
+        .collect_vec();
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+    let mut seen: HashSet<Vec<Pos>> = HashSet::new();
+
+    // One frame per spin cycle, until the rocks settle into a repeating state.
+    Animator::new()
+        .delay(Duration::from_millis(200))
+        .interactive(true)
+        .run(|_| {
+            if !seen.insert(get_grid_state(&grid)) {
+                return None;
+            }
+            let frame = GridRenderer::new().render_to_string(&grid);
+            perform_cycle(&mut grid, max_x, max_y);
+            Some(frame)
+        })
+        .unwrap();
+}
+
+fn perform_cycle(grid: &mut [Vec<Cell>], max_x: usize, max_y: usize) {
+    for i in 0..max_x {
+        for j in 0..max_y {
+            if grid[i][j] == Cell::Rounded {
+                roll_north_along_column(grid, i, j);
+            }
+        }
+    }
+    for i in 0..max_x {
+        for j in 0..max_y {
+            if grid[i][j] == Cell::Rounded {
+                roll_west_along_row(grid, i, j);
+            }
+        }
+    }
+    for i in (0..max_x).rev() {
+        for j in 0..max_y {
+            if grid[i][j] == Cell::Rounded {
+                roll_south_along_column(grid, i, j);
+            }
+        }
+    }
+    for i in 0..max_x {
+        for j in (0..max_y).rev() {
+            if grid[i][j] == Cell::Rounded {
+                roll_east_along_row(grid, i, j);
+            }
+        }
+    }
+}
+
+fn roll_north_along_column(grid: &mut [Vec<Cell>], row: usize, column: usize) -> usize {
+    let mut current_row = row as isize;
+
+    while current_row > 0 && grid[(current_row - 1) as usize][column] == Cell::Empty {
+        // dbg!(current_row, column);
+        // dbg!(grid[current_row as usize][column]);
+        current_row -= 1;
+    }
+
+    if current_row >= 0 && grid[current_row as usize][column] == Cell::Empty {
+        grid[current_row as usize][column] = grid[row][column];
+        grid[row][column] = Cell::Empty;
+        return current_row as usize;
+    }
+
+    row
+}
+
+fn roll_west_along_row(grid: &mut [Vec<Cell>], row: usize, column: usize) -> usize {
+    let mut current_col = column as isize;
+
+    while current_col > 0 && grid[row][(current_col - 1) as usize] == Cell::Empty {
+        current_col -= 1;
+    }
+
+    if current_col >= 0 && grid[row][current_col as usize] == Cell::Empty {
+        grid[row][current_col as usize] = grid[row][column];
+        grid[row][column] = Cell::Empty;
+        return current_col as usize;
+    }
+
+    column
+}
+
+fn roll_south_along_column(grid: &mut [Vec<Cell>], row: usize, column: usize) -> usize {
+    let mut current_row = row;
+
+    while current_row + 1 < grid.len() && grid[current_row + 1][column] == Cell::Empty {
+        // dbg!(current_row, column);
+        // dbg!(grid[current_row as usize][column]);
+        current_row += 1;
+    }
+
+    if current_row < grid.len() && grid[current_row][column] == Cell::Empty {
+        grid[current_row][column] = grid[row][column];
+        grid[row][column] = Cell::Empty;
+        return current_row;
+    }
+
+    row
+}
+
+fn roll_east_along_row(grid: &mut [Vec<Cell>], row: usize, column: usize) -> usize {
+    let mut current_col = column;
+
+    while current_col + 1 < grid.len() && grid[row][current_col + 1] == Cell::Empty {
+        current_col += 1;
+    }
+
+    if current_col < grid.len() && grid[row][current_col] == Cell::Empty {
+        grid[row][current_col] = grid[row][column];
+        grid[row][column] = Cell::Empty;
+        return current_col;
+    }
+
+    column
+}
+
+fn get_grid_state(grid: &[Vec<Cell>]) -> Vec<Pos> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c == Cell::Rounded)
+                .map(move |(j, _)| (i, j))
+        })
+        .collect_vec()
+}
+
+/// Moves every rounded rock one cell in direction `(dx, dy)` until none can move.
+fn reference_tilt(grid: &mut [Vec<char>], (dx, dy): (isize, isize)) {
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for x in 0..rows {
+            for y in 0..cols {
+                let (nx, ny) = (x + dx, y + dy);
+                if grid[x as usize][y as usize] == 'O'
+                    && (0..rows).contains(&nx)
+                    && (0..cols).contains(&ny)
+                    && grid[nx as usize][ny as usize] == '.'
+                {
+                    grid[nx as usize][ny as usize] = 'O';
+                    grid[x as usize][y as usize] = '.';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn reference_load(grid: &[Vec<char>]) -> usize {
+    grid.iter()
+        .enumerate()
+        .map(|(i, row)| (grid.len() - i) * row.iter().filter(|&&c| c == 'O').count())
+        .sum()
+}
+
+/// Reference for [`part1`], tilting one cell at a time.
+pub fn reference_part1(input: &str) -> usize {
+    let mut grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+    reference_tilt(&mut grid, (-1, 0));
+    reference_load(&grid)
+}
+
+/// Reference for [`part2`], spinning one cell at a time and remembering every grid seen.
+pub fn reference_part2(input: &str) -> usize {
+    const SPINS: usize = 1_000_000_000;
+
+    let mut grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+    let mut seen: HashMap<Vec<Vec<char>>, usize> = HashMap::new();
+    let mut spin = 0;
+    while spin < SPINS {
+        if let Some(first) = seen.insert(grid.clone(), spin) {
+            let period = spin - first;
+            spin += (SPINS - spin) / period * period;
+            seen.clear();
+            if spin == SPINS {
+                break;
+            }
+        }
+        for direction in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            reference_tilt(&mut grid, direction);
+        }
+        spin += 1;
+    }
+    reference_load(&grid)
+}
@@ -0,0 +1,88 @@
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> usize {
+    input
+        .split(',')
+        .map(|s| hash(&s.chars().collect_vec()))
+        .sum()
+}
+
+pub fn part2(input: &str) -> usize {
+    let steps = input.split(',').collect_vec();
+
+    let mut hashmap: Vec<IndexMap<&str, usize>> = vec![IndexMap::new(); 256];
+
+    for step in &steps {
+        if step.contains('=') {
+            let (name, power_str) = step.split('=').collect_tuple().unwrap();
+            let hash = hash(&name.chars().collect_vec());
+            let power = power_str.parse().unwrap();
+            hashmap[hash]
+                .entry(name)
+                .and_modify(|e| *e = power)
+                .or_insert(power);
+        } else {
+            // -
+            let (name, _) = step.split('-').collect_tuple().unwrap();
+            let hash = hash(&name.chars().collect_vec());
+            hashmap[hash].shift_remove(&name);
+        }
+    }
+
+    let mut sum = 0;
+    for (i, map) in hashmap.iter().enumerate() {
+        sum += map
+            .iter()
+            .enumerate()
+            .map(|(j, e)| (i + 1) * (j + 1) * e.1)
+            .sum::<usize>();
+    }
+
+    sum
+}
+
+fn hash(chars: &[char]) -> usize {
+    let mut hash: usize = 0;
+    for c in chars {
+        hash += (*c) as usize;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
+
+/// The HASH algorithm over the bytes of `s`, using wrapping `u8` arithmetic.
+fn reference_hash(s: &str) -> usize {
+    s.bytes().fold(0u8, |h, b| h.wrapping_add(b).wrapping_mul(17)) as usize
+}
+
+/// Reference for [`part1`], hashing with byte arithmetic.
+pub fn reference_part1(input: &str) -> usize {
+    input.trim().split(',').map(reference_hash).sum()
+}
+
+/// Reference for [`part2`], keeping each box as a plain list of lenses.
+pub fn reference_part2(input: &str) -> usize {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![vec![]; 256];
+
+    for step in input.trim().split(',') {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[reference_hash(label)].retain(|lens| lens.0 != label);
+        } else {
+            let (label, focal) = step.split_once('=').unwrap();
+            let focal = focal.parse().unwrap();
+            let lenses = &mut boxes[reference_hash(label)];
+            match lenses.iter_mut().find(|lens| lens.0 == label) {
+                Some(lens) => lens.1 = focal,
+                None => lenses.push((label, focal)),
+            }
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(b, lenses)| lenses.iter().enumerate().map(move |(slot, lens)| (b + 1) * (slot + 1) * lens.1))
+        .sum()
+}
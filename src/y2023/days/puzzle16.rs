@@ -0,0 +1,233 @@
+use itertools::Itertools;
+use crate::utils::anim::Animator;
+use crate::utils::image::{Image, Scale};
+use crate::utils::render::{Color, GridRenderer};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+struct Pos(usize, usize);
+
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+type Beam = (Pos, Direction);
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect_vec()).collect_vec()
+}
+
+pub fn part1(input: &str) -> usize {
+    run_beam((Pos(0, 0), Direction::Right), &parse(input)).len()
+}
+
+pub fn part2(input: &str) -> usize {
+    let grid = parse(input);
+    let mut candidates: Vec<Beam> = vec![];
+
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+    (0..max_x)
+        .map(|i| (Pos(i, 0), Direction::Right))
+        .for_each(|c| candidates.push(c));
+    (0..max_x)
+        .map(|i| (Pos(i, max_y - 1), Direction::Left))
+        .for_each(|c| candidates.push(c));
+    (0..max_y)
+        .map(|i| (Pos(0, i), Direction::Down))
+        .for_each(|c| candidates.push(c));
+    (0..max_y)
+        .map(|i| (Pos(max_x - 1, i), Direction::Up))
+        .for_each(|c| candidates.push(c));
+
+    let mut max_energized_tile_count = usize::MIN;
+
+    for c in candidates {
+        let energized_tiles = run_beam(c, &grid);
+        max_energized_tile_count = max_energized_tile_count.max(energized_tiles.len());
+    }
+
+    max_energized_tile_count
+}
+
+/// How often each tile was crossed by the part 1 beam.
+pub fn heatmap(input: &str) -> Image {
+    let grid = parse(input);
+    let energized_tiles = run_beam((Pos(0, 0), Direction::Right), &grid);
+    let counts = energized_tiles.iter().map(|(p, count)| ((p.0, p.1), *count));
+    Image::heatmap(grid[0].len(), grid.len(), counts, Scale::Linear)
+}
+
+pub fn animate_beam(input: &str) {
+    let grid = &parse(input);
+    let mut energized_tiles: HashMap<Pos, usize> = HashMap::new();
+    let mut current_beams: Vec<Beam> = vec![(Pos(0, 0), Direction::Right)];
+    let mut seen: HashSet<Beam> = HashSet::new();
+
+    Animator::new()
+        .interactive(true)
+        .run(|_| {
+            if current_beams.is_empty() {
+                return None;
+            }
+            let heads = |d: Direction| {
+                current_beams
+                    .iter()
+                    .filter(move |b| b.1 == d)
+                    .map(|b| (b.0 .0, b.0 .1))
+            };
+            let frame = GridRenderer::new()
+                .highlight(energized_tiles.keys().map(|p| (p.0, p.1)), Color::Yellow)
+                .overlay_colored(heads(Direction::Up), '^', Color::Red)
+                .overlay_colored(heads(Direction::Down), 'v', Color::Red)
+                .overlay_colored(heads(Direction::Left), '<', Color::Red)
+                .overlay_colored(heads(Direction::Right), '>', Color::Red)
+                .ansi(true)
+                .render_to_string(grid);
+            current_beams = step_beams(&current_beams, grid, &mut seen, &mut energized_tiles);
+            Some(frame)
+        })
+        .unwrap();
+}
+
+fn run_beam(starting_beam: Beam, grid: &[Vec<char>]) -> HashMap<Pos, usize> {
+    let mut energized_tiles: HashMap<Pos, usize> = HashMap::new();
+    let mut current_beams: Vec<Beam> = vec![starting_beam];
+    let mut seen: HashSet<Beam> = HashSet::new();
+
+    while !current_beams.is_empty() {
+        current_beams = step_beams(&current_beams, grid, &mut seen, &mut energized_tiles);
+    }
+    energized_tiles
+}
+
+/// Advances every beam by one tile, energizing the tiles they were on.
+/// Returns the beams that have not been seen before.
+fn step_beams(
+    current_beams: &[Beam],
+    grid: &[Vec<char>],
+    seen: &mut HashSet<Beam>,
+    energized_tiles: &mut HashMap<Pos, usize>,
+) -> Vec<Beam> {
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+    let mut next_beams = vec![];
+    for b in current_beams {
+        match (grid[b.0 .0][b.0 .1], b.1) {
+            ('.' | '|', Direction::Up)
+            | ('/', Direction::Right)
+            | ('\\', Direction::Left) => {
+                move_beam_up(&mut next_beams, b);
+            }
+            ('.' | '|', Direction::Down)
+            | ('/', Direction::Left)
+            | ('\\', Direction::Right) => {
+                move_beam_down(&mut next_beams, b, max_x);
+            },
+            ('.' | '-', Direction::Left) | ('/', Direction::Down) | ('\\', Direction::Up) => {
+                move_beam_left(&mut next_beams, b);
+            }
+            ('.' | '-', Direction::Right) | ('/', Direction::Up) | ('\\', Direction::Down) => {
+                move_beam_right(&mut next_beams, b, max_y);
+            }
+            ('|', Direction::Left | Direction::Right) => {
+                move_beam_up(&mut next_beams, b);
+                move_beam_down(&mut next_beams, b, max_x);
+            }
+            ('-', Direction::Up | Direction::Down) => {
+                move_beam_right(&mut next_beams, b, max_y);
+                move_beam_left(&mut next_beams, b);
+            }
+            _ => unreachable!(),
+        }
+        energized_tiles
+            .entry(b.0)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+    }
+    let mut unseen_beams = vec![];
+    for nb in &next_beams {
+        if !seen.contains(nb) {
+            unseen_beams.push(*nb);
+            seen.insert(*nb);
+        }
+    }
+    unseen_beams
+}
+
+fn move_beam_right(next_beams: &mut Vec<(Pos, Direction)>, b: &Beam, max_y: usize) {
+    if b.0 .1 + 1 < max_y {
+        next_beams.push((Pos(b.0 .0, b.0 .1 + 1), Direction::Right));
+    }
+}
+
+fn move_beam_left(next_beams: &mut Vec<(Pos, Direction)>, b: &Beam) {
+    if b.0 .1 > 0 {
+        next_beams.push((Pos(b.0 .0, b.0 .1 - 1), Direction::Left));
+    }
+}
+
+fn move_beam_down(next_beams: &mut Vec<(Pos, Direction)>, b: &Beam, max_x: usize) {
+    if b.0 .0 + 1 < max_x {
+        next_beams.push((Pos(b.0 .0 + 1, b.0 .1), Direction::Down));
+    }
+}
+
+fn move_beam_up(next_beams: &mut Vec<(Pos, Direction)>, b: &Beam) {
+    if b.0 .0 > 0 {
+        next_beams.push((Pos(b.0 .0 - 1, b.0 .1), Direction::Up));
+    }
+}
+
+/// The number of tiles energized by a beam entering at `(x, y)` heading `(dx, dy)`, following
+/// every beam one tile at a time and remembering each `(tile, heading)` visited.
+fn reference_energized(grid: &[Vec<char>], start: (isize, isize, isize, isize)) -> usize {
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some((x, y, dx, dy)) = stack.pop() {
+        if !(0..rows).contains(&x) || !(0..cols).contains(&y) || !visited.insert((x, y, dx, dy)) {
+            continue;
+        }
+        let headings = match grid[x as usize][y as usize] {
+            '/' => vec![(-dy, -dx)],
+            '\\' => vec![(dy, dx)],
+            '|' if dy != 0 => vec![(-1, 0), (1, 0)],
+            '-' if dx != 0 => vec![(0, -1), (0, 1)],
+            _ => vec![(dx, dy)],
+        };
+        for (dx, dy) in headings {
+            stack.push((x + dx, y + dy, dx, dy));
+        }
+    }
+
+    visited.iter().map(|&(x, y, _, _)| (x, y)).unique().count()
+}
+
+/// Reference for [`part1`], tracing the beam tile by tile.
+pub fn reference_part1(input: &str) -> usize {
+    reference_energized(&parse(input), (0, 0, 0, 1))
+}
+
+/// Reference for [`part2`], tracing a beam from every edge tile in every inward heading.
+pub fn reference_part2(input: &str) -> usize {
+    let grid = parse(input);
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+
+    let mut starts = vec![];
+    for x in 0..rows {
+        starts.push((x, 0, 0, 1));
+        starts.push((x, cols - 1, 0, -1));
+    }
+    for y in 0..cols {
+        starts.push((0, y, 1, 0));
+        starts.push((rows - 1, y, -1, 0));
+    }
+
+    starts.into_iter().map(|s| reference_energized(&grid, s)).max().unwrap()
+}
@@ -0,0 +1,165 @@
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+type Pos = (usize, usize);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Node {
+    position: Pos,
+    cost: usize,
+    direction: (i32, i32),
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect_vec()).collect_vec()
+}
+
+pub fn part1(input: &str) -> usize {
+    find_shortest_path_bfs(&parse(input), (0, 0), 1, 3)
+}
+
+pub fn part2(input: &str) -> usize {
+    find_shortest_path_bfs(&parse(input), (0, 0), 4, 10)
+}
+
+/// The least heat loss to the bottom right corner, relaxing single steps over
+/// `(row, column, heading, run length)` states until nothing improves. Like the real solver,
+/// returns 0 when the corner cannot be reached.
+fn reference_heat_loss(input: &str, min_run: usize, max_run: usize) -> usize {
+    const HEADINGS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    let grid = parse(input)
+        .iter()
+        .map(|row| row.iter().map(|c| c.to_digit(10).unwrap() as usize).collect_vec())
+        .collect_vec();
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+
+    // The start has no heading yet, so it may set off in any direction (or already be the end).
+    let mut best: HashMap<(isize, isize, usize, usize), usize> = HashMap::new();
+    for heading in 0..4 {
+        best.insert((0, 0, heading, 0), 0);
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (&(x, y, heading, run), &loss) in best.clone().iter() {
+            for (next, (dx, dy)) in HEADINGS.into_iter().enumerate() {
+                let turning = next != heading;
+                if (next + 2) % 4 == heading && run > 0
+                    || turning && run > 0 && run < min_run
+                    || !turning && run == max_run
+                {
+                    continue;
+                }
+                let (nx, ny) = (x + dx, y + dy);
+                if !(0..rows).contains(&nx) || !(0..cols).contains(&ny) {
+                    continue;
+                }
+                let state = (nx, ny, next, if turning && run > 0 { 1 } else { run + 1 });
+                let candidate = loss + grid[nx as usize][ny as usize];
+                if best.get(&state).is_none_or(|&b| candidate < b) {
+                    best.insert(state, candidate);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    best.iter()
+        .filter(|(&(x, y, _, run), _)| x == rows - 1 && y == cols - 1 && (run == 0 || run >= min_run))
+        .map(|(_, &loss)| loss)
+        .min()
+        .unwrap_or(0)
+}
+
+/// Reference for [`part1`], relaxing single steps until the losses settle.
+pub fn reference_part1(input: &str) -> usize {
+    reference_heat_loss(input, 1, 3)
+}
+
+/// Reference for [`part2`], relaxing single steps of an ultra crucible until the losses settle.
+pub fn reference_part2(input: &str) -> usize {
+    reference_heat_loss(input, 4, 10)
+}
+
+fn find_shortest_path_bfs(grid: &[Vec<char>], source: Pos, min_dist: usize, max_dist: usize) -> usize {
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    let mut dist: HashMap<(Pos, (i32, i32)), usize> = HashMap::new();
+    let mut prev: HashMap<Pos, Pos> = HashMap::new();
+
+
+    let mut heap = BinaryHeap::new();
+
+    heap.push(Node {
+        position: source,
+        cost: 0,
+        direction: (0, 0),
+    });
+
+    while let Some(Node {
+        position,
+        cost,
+        direction,
+    }) = heap.pop()
+    {
+        if position == (max_x - 1, max_y - 1) {
+            return cost;
+        }
+
+        if dist.get(&(position, direction)).is_some_and(|&c| cost > c) {
+            continue;
+        }
+
+        for (dx, dy) in [(0, -1), (0, 1), (1, 0), (-1, 0)] {
+
+            if direction == (dx, dy) || direction == (-dx, -dy) {
+                continue;
+            }
+
+            let mut next_cost = cost;
+
+            for d in 1..=max_dist as i32 {
+                let neighbor = ((position.0 as i32 + dx * d) as usize, (position.1 as i32 + dy * d) as usize);
+                if neighbor.0 >= max_x || neighbor.1 >= max_y {
+                    continue;
+                }
+
+                next_cost += grid[neighbor.0][neighbor.1].to_digit(10).unwrap() as usize;
+                let next = Node {
+                    position: neighbor,
+                    cost: next_cost,
+                    direction: (dx, dy),
+                };
+
+                if min_dist <= d as usize && next.cost < *dist.get(&(neighbor, (dx, dy))).unwrap_or(&usize::MAX) {
+                    heap.push(next);
+                    prev.entry(neighbor)
+                        .and_modify(|e| *e = position)
+                        .or_insert(position);
+                    dist.insert((next.position, next.direction), next.cost);
+                }
+            }
+        }
+    }
+
+    0
+}
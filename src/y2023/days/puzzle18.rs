@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use crate::utils::svg::Svg;
+use tap::Pipe;
+
+#[derive(Debug)]
+struct Pos(isize, isize);
+
+/// The lagoon volume for the plain and the corrected instructions, and the plain trench outline.
+fn scan(input: &str) -> (isize, isize, Vec<(f64, f64)>) {
+    let lines = input.lines().collect_vec();
+
+    let mut current_pos = Pos(0, 0);
+    let mut trench = vec![(0.0, 0.0)];
+    let mut sum1 = 0;
+    let mut sum2 = 0;
+    let mut sum_dir = 0;
+
+    let mut current_pos_corr = Pos(0, 0);
+    let mut sum1_corr = 0;
+    let mut sum2_corr = 0;
+    let mut sum_dir_corr = 0;
+
+    for line in lines {
+        let (dir, count, color) = line.split_ascii_whitespace().collect_tuple().unwrap();
+        let count: isize = count.parse().unwrap();
+        let (corrected_count, direction): (isize, usize) = color
+            .strip_prefix("(#")
+            .unwrap()
+            .strip_suffix(')')
+            .unwrap()
+            .pipe(|c| c.split_at(5))
+            .pipe(|(hex_len, dir)| {
+                (
+                    isize::from_str_radix(hex_len, 16).unwrap(),
+                    dir.parse().unwrap(),
+                )
+            });
+
+        let orig_direction = match dir {
+            "R" => (1isize, 0isize),
+            "L" => (-1, 0),
+            "D" => (0, -1),
+            "U" => (0, 1),
+            _ => unreachable!(),
+        };
+
+        let next_pos = Pos(
+            current_pos.0 + orig_direction.0 * count,
+            current_pos.1 + orig_direction.1 * count,
+        );
+        // Sum_n(x^iy^i+1 - x^i+1y^i)
+        sum1 += current_pos.0 * next_pos.1;
+        sum2 += current_pos.1 * next_pos.0;
+        sum_dir += count;
+        // SVG y grows downwards, so flip U/D to keep the plan upright.
+        trench.push((next_pos.0 as f64, -next_pos.1 as f64));
+        current_pos = next_pos;
+
+        let corr_direction = match direction {
+            0 => (1isize, 0isize),
+            1 => (0, 1),
+            2 => (-1, 0),
+            3 => (0, -1),
+            _ => unreachable!(),
+        };
+
+        let next_pos_corr = Pos(
+            current_pos_corr.0 + corr_direction.0 * corrected_count,
+            current_pos_corr.1 + corr_direction.1 * corrected_count,
+        );
+        sum1_corr += current_pos_corr.0 * next_pos_corr.1;
+        sum2_corr += current_pos_corr.1 * next_pos_corr.0;
+        sum_dir_corr += corrected_count;
+        current_pos_corr = next_pos_corr;
+    }
+
+    let area1 = (sum1 - sum2).abs() / 2;
+    let area2 = (sum1_corr - sum2_corr).abs() / 2;
+
+    (area1 + sum_dir / 2 + 1, area2 + sum_dir_corr / 2 + 1, trench)
+}
+
+pub fn part1(input: &str) -> isize {
+    scan(input).0
+}
+
+pub fn part2(input: &str) -> isize {
+    scan(input).1
+}
+
+pub fn draw_trench(input: &str) -> Svg {
+    let mut trench = scan(input).2;
+    // The trench is closed, so the last vertex repeats the first one.
+    trench.pop();
+    let mut svg = Svg::new();
+    svg.polygon_with_labels(&trench, "#f4e3c1", "#8b4513");
+    svg
+}
+
+/// Digs the trench on a grid compressed to the corners of the plan, flood fills the outside
+/// and counts every cubic metre that is left.
+fn reference_volume(steps: &[((isize, isize), isize)]) -> isize {
+    let mut corners = vec![(0, 0)];
+    for &((dx, dy), len) in steps {
+        let &(x, y) = corners.last().unwrap();
+        corners.push((x + dx * len, y + dy * len));
+    }
+
+    // Each compressed cell spans `cuts[i]..cuts[i + 1]`, with an empty border all around.
+    let cuts = |coords: Vec<isize>| {
+        let mut cuts = coords.iter().flat_map(|&c| [c, c + 1]).collect_vec();
+        cuts.push(coords.iter().min().unwrap() - 1);
+        cuts.push(coords.iter().max().unwrap() + 2);
+        cuts.sort_unstable();
+        cuts.dedup();
+        cuts
+    };
+    let xs = cuts(corners.iter().map(|c| c.0).collect());
+    let ys = cuts(corners.iter().map(|c| c.1).collect());
+    let (w, h) = (xs.len() - 1, ys.len() - 1);
+
+    let mut trench = HashSet::new();
+    for (a, b) in corners.iter().tuple_windows() {
+        let inside = |lo: isize, hi: isize, v: isize| lo.min(hi) <= v && v <= lo.max(hi);
+        for (i, &x) in xs[..w].iter().enumerate() {
+            for (j, &y) in ys[..h].iter().enumerate() {
+                if inside(a.0, b.0, x) && inside(a.1, b.1, y) {
+                    trench.insert((i, j));
+                }
+            }
+        }
+    }
+
+    let mut outside = HashSet::from([(0, 0)]);
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    while let Some((i, j)) = stack.pop() {
+        let neighbours = [(i.wrapping_sub(1), j), (i + 1, j), (i, j.wrapping_sub(1)), (i, j + 1)];
+        for (ni, nj) in neighbours {
+            if ni < w && nj < h && !trench.contains(&(ni, nj)) && outside.insert((ni, nj)) {
+                stack.push((ni, nj));
+            }
+        }
+    }
+
+    (0..w)
+        .flat_map(|i| (0..h).map(move |j| (i, j)))
+        .filter(|cell| !outside.contains(cell))
+        .map(|(i, j)| (xs[i + 1] - xs[i]) * (ys[j + 1] - ys[j]))
+        .sum()
+}
+
+/// Reference for [`part1`], flood filling around the plain instructions.
+pub fn reference_part1(input: &str) -> isize {
+    let steps = input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let heading = match parts.next().unwrap() {
+                "R" => (1, 0),
+                "D" => (0, 1),
+                "L" => (-1, 0),
+                _ => (0, -1),
+            };
+            (heading, parts.next().unwrap().parse().unwrap())
+        })
+        .collect_vec();
+    reference_volume(&steps)
+}
+
+/// Reference for [`part2`], flood filling around the instructions hidden in the colours.
+pub fn reference_part2(input: &str) -> isize {
+    let steps = input
+        .lines()
+        .map(|line| {
+            let hex = &line[line.find('#').unwrap() + 1..line.len() - 1];
+            let heading = [(1, 0), (0, 1), (-1, 0), (0, -1)][hex[5..].parse::<usize>().unwrap()];
+            (heading, isize::from_str_radix(&hex[..5], 16).unwrap())
+        })
+        .collect_vec();
+    reference_volume(&steps)
+}
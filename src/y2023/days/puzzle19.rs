@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::utils::dot::{Dot, Shape};
+use crate::utils::parse::{blocks, captures, key_values};
+
+lazy_static! {
+    static ref WORKFLOW_PAT: Regex = Regex::new("(\\w+)\\{(.*)\\}").unwrap();
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+enum Quality {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl From<&str> for Quality {
+    fn from(value: &str) -> Self {
+        match value {
+            "x" => Self::X,
+            "m" => Self::M,
+            "a" => Self::A,
+            "s" => Self::S,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Quality {
+    fn name(&self) -> &'static str {
+        match &self {
+            Quality::X => "x",
+            Quality::M => "m",
+            Quality::A => "a",
+            Quality::S => "s",
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+enum Operation {
+    GreaterThan,
+    LessThan,
+}
+
+impl From<&str> for Operation {
+    fn from(value: &str) -> Self {
+        match value {
+            ">" => Self::GreaterThan,
+            "<" => Self::LessThan,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Operation {
+    fn symbol(&self) -> &'static str {
+        match &self {
+            Operation::GreaterThan => ">",
+            Operation::LessThan => "<",
+        }
+    }
+
+    fn func(&self, x1: usize, x2: usize) -> bool {
+        match &self {
+            Operation::GreaterThan => { x1 > x2 }
+            Operation::LessThan => { x1 < x2 }
+        }
+    }
+
+    fn func_range(&self, start: usize, end: usize, threshold: usize) -> ((usize, usize), (usize, usize)) {
+        match &self {
+            Operation::GreaterThan => ((start, threshold), (threshold + 1, end)),
+            Operation::LessThan => ((threshold, end), (start, threshold - 1))
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+enum Condition {
+    Simple(String),
+    Complex {
+        q: Quality,
+        op: Operation,
+        threshold: usize,
+        result: String,
+    },
+}
+
+impl Condition {
+    fn evaluate(&self, part: &Part) -> bool {
+        match self {
+            Condition::Simple(_result) => true,
+            Condition::Complex {
+                q,
+                op,
+                threshold,
+                result: _result,
+            } => {
+                let quality = match q {
+                    Quality::X => part.x,
+                    Quality::M => part.m,
+                    Quality::A => part.a,
+                    Quality::S => part.s,
+                };
+                op.func(quality, *threshold)
+            }
+        }
+    }
+
+    fn evaluate_set(&self, ps: &PartSet) -> (Option<PartSet>, PartSet, String) {
+        match &self {
+            &Condition::Complex {
+                q,
+                op,
+                threshold,
+                result,
+            } => {
+                let new_part_set = match q {
+                    Quality::X => {
+                        let (m, a, s) = (ps.m, ps.a, ps.s);
+                        let x = op.func_range(ps.x.0, ps.x.1, *threshold);
+                        (PartSet { x: x.0, m, a, s }, PartSet { x: x.1, m, a, s })
+                    }
+                    Quality::M => {
+                        let (x, a, s) = (ps.x, ps.a, ps.s);
+                        let m = op.func_range(ps.m.0, ps.m.1, *threshold);
+                        (PartSet { x, m: m.0, a, s }, PartSet { x, m: m.1, a, s })
+                    }
+                    Quality::A => {
+                        let (x, m, s) = (ps.x, ps.m, ps.s);
+                        let a = op.func_range(ps.a.0, ps.a.1, *threshold);
+                        (PartSet { x, m, a: a.0, s }, PartSet { x, m, a: a.1, s })
+                    }
+                    Quality::S => {
+                        let (x, m, a) = (ps.x, ps.m, ps.a);
+                        let s = op.func_range(ps.s.0, ps.s.1, *threshold);
+                        (PartSet { x, m, a, s: s.0 }, PartSet { x, m, a, s: s.1 })
+                    }
+                };
+                (Some(new_part_set.0), new_part_set.1, result.clone())
+            }
+            Condition::Simple(result) => (None, *ps, result.clone()),
+        }
+    }
+
+    fn result(&self) -> &str {
+        match &self {
+            Condition::Simple(result) => result,
+            Condition::Complex { result, .. } => result,
+        }
+    }
+
+    /// The comparison as written in the input, e.g. `a<2006`. Fallbacks have none.
+    fn label(&self) -> Option<String> {
+        match &self {
+            Condition::Simple(_) => None,
+            Condition::Complex { q, op, threshold, .. } => Some(format!("{}{}{threshold}", q.name(), op.symbol())),
+        }
+    }
+}
+
+impl From<&str> for Condition {
+    fn from(value: &str) -> Self {
+        if value.contains('>') {
+            let parts = value.split('>').collect_vec();
+            let (threshold, result) = parts[1].split(':').collect_tuple().unwrap();
+            return Self::Complex {
+                q: parts[0].into(),
+                op: Operation::GreaterThan,
+                threshold: threshold.parse().unwrap(),
+                result: result.to_owned(),
+            };
+        } else if value.contains('<') {
+            let parts = value.split('<').collect_vec();
+            let (threshold, result) = parts[1].split(':').collect_tuple().unwrap();
+            return Self::Complex {
+                q: parts[0].into(),
+                op: Operation::LessThan,
+                threshold: threshold.parse().unwrap(),
+                result: result.to_owned(),
+            };
+        }
+
+        Self::Simple(value.to_owned())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Workflow<'a> {
+    name: &'a str,
+    conditions: Vec<Condition>,
+}
+
+impl Workflow<'_> {
+    fn evaluate(&self, part: &Part) -> String {
+        for c in &self.conditions {
+            if c.evaluate(part) {
+                return match c {
+                    Condition::Simple(r) => r.clone(),
+                    Condition::Complex {
+                        q: _q,
+                        op: _op,
+                        threshold: _threshold,
+                        result,
+                    } => result.clone(),
+                };
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn evaluate_set(&self, ps: &PartSet) -> Vec<(PartSet, String)> {
+        let mut remaining = *ps;
+        let mut result_parts = vec![];
+        for c in &self.conditions {
+            let (rem, success, success_result) = c.evaluate_set(&remaining);
+            result_parts.push((success, success_result));
+            if let Some(r) = rem {
+                remaining = r;
+            } else {
+                break;
+            }
+        }
+
+        result_parts
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct PartSet {
+    x: (usize, usize),
+    m: (usize, usize),
+    a: (usize, usize),
+    s: (usize, usize),
+}
+
+impl PartSet {
+    fn get_count(&self) -> usize {
+        (self.x.1 - self.x.0 + 1)
+            * (self.m.1 - self.m.0 + 1)
+            * (self.a.1 - self.a.0 + 1)
+            * (self.s.1 - self.s.0 + 1)
+    }
+}
+
+fn parse(input: &str) -> (HashMap<String, Workflow<'_>>, Vec<Part>) {
+    let (workflows, parts) = blocks(input).into_iter().collect_tuple().unwrap();
+
+    let mut workflow_map: HashMap<String, Workflow> = HashMap::new();
+
+    for line in workflows.lines() {
+        let (name, conditions) = captures(&WORKFLOW_PAT, line).unwrap().into_iter().collect_tuple().unwrap();
+        let workflow = Workflow {
+            name,
+            conditions: conditions
+                .split(',')
+                .map(std::convert::Into::into)
+                .collect_vec(),
+        };
+        workflow_map.insert(name.to_string(), workflow);
+    }
+
+    let mut parts_list: Vec<Part> = vec![];
+
+    for line in parts.lines() {
+        let ratings: HashMap<&str, usize> = key_values(line).unwrap().into_iter().collect();
+        parts_list.push(Part {
+            x: ratings["x"],
+            m: ratings["m"],
+            a: ratings["a"],
+            s: ratings["s"],
+        });
+    }
+
+    (workflow_map, parts_list)
+}
+
+pub fn part1(input: &str) -> usize {
+    let (workflow_map, parts_list) = parse(input);
+
+    let mut accepted_parts = vec![];
+    for p in &parts_list {
+        let mut curr_result = workflow_map.get("in").unwrap().evaluate(p);
+        loop {
+            let w = workflow_map.get(&curr_result).unwrap();
+            curr_result = w.evaluate(p);
+
+            if curr_result == "A" || curr_result == "R" {
+                break;
+            }
+        }
+
+        if curr_result == "A" {
+            accepted_parts.push(*p);
+        }
+    }
+
+    accepted_parts.iter().map(|p| p.x + p.m + p.a + p.s).sum()
+}
+
+pub fn part2(input: &str) -> usize {
+    let (workflow_map, _) = parse(input);
+
+    let mut curr_part_sets = vec![(
+        PartSet {
+            x: (1, 4000),
+            m: (1, 4000),
+            a: (1, 4000),
+            s: (1, 4000),
+        },
+        "in".to_owned(),
+    )];
+
+    let mut sum2: usize = 0;
+
+    while !curr_part_sets.is_empty() {
+        let mut next_part_sets = vec![];
+
+        for psr in &curr_part_sets {
+            let w = workflow_map.get(&psr.1).unwrap();
+            next_part_sets.extend_from_slice(&w.evaluate_set(&psr.0));
+        }
+        curr_part_sets.clear();
+        for psr in &next_part_sets {
+            if psr.1 == "A" {
+                sum2 += psr.0.get_count();
+            }
+
+            if psr.1 != "A" && psr.1 != "R" {
+                curr_part_sets.push(psr.clone());
+            }
+        }
+    }
+
+    sum2
+}
+
+/// A rule as `(category index, comparison, threshold, target)`; fallbacks have no comparison.
+type Rule<'a> = (Option<(usize, char, usize)>, &'a str);
+
+fn reference_workflows(input: &str) -> HashMap<&str, Vec<Rule<'_>>> {
+    let (workflows, _) = input.split_once("\n\n").unwrap();
+    workflows
+        .lines()
+        .map(|line| {
+            let (name, rules) = line.trim_end_matches('}').split_once('{').unwrap();
+            let rules = rules
+                .split(',')
+                .map(|rule| match rule.split_once(':') {
+                    Some((test, target)) => {
+                        let category = "xmas".find(&test[..1]).unwrap();
+                        let cmp = test.as_bytes()[1] as char;
+                        (Some((category, cmp, test[2..].parse().unwrap())), target)
+                    }
+                    None => (None, rule),
+                })
+                .collect();
+            (name, rules)
+        })
+        .collect()
+}
+
+/// Follows a part from `in` until it is accepted or rejected.
+fn reference_accepts(workflows: &HashMap<&str, Vec<Rule>>, part: [usize; 4]) -> bool {
+    let mut current = "in";
+    while current != "A" && current != "R" {
+        current = workflows[current]
+            .iter()
+            .find(|(test, _)| match *test {
+                None => true,
+                Some((c, '<', t)) => part[c] < t,
+                Some((c, _, t)) => part[c] > t,
+            })
+            .unwrap()
+            .1;
+    }
+    current == "A"
+}
+
+/// Reference for [`part1`], sending each part through the rules as written.
+pub fn reference_part1(input: &str) -> usize {
+    let workflows = reference_workflows(input);
+    let (_, parts) = input.split_once("\n\n").unwrap();
+
+    parts
+        .lines()
+        .map(|line| {
+            let values = line
+                .trim_matches(|c| c == '{' || c == '}')
+                .split(',')
+                .map(|kv| kv[2..].parse().unwrap())
+                .collect_vec();
+            [values[0], values[1], values[2], values[3]]
+        })
+        .filter(|&part| reference_accepts(&workflows, part))
+        .map(|part| part.iter().sum::<usize>())
+        .sum()
+}
+
+/// Reference for [`part2`]. Every threshold splits its category into intervals that all rules
+/// treat alike, so sending one part per combination of intervals is enough.
+pub fn reference_part2(input: &str) -> usize {
+    let workflows = reference_workflows(input);
+
+    // Each category is cut into intervals starting at these values.
+    let mut starts = vec![vec![1, 4001]; 4];
+    for (test, _) in workflows.values().flatten() {
+        match *test {
+            Some((c, '<', t)) => starts[c].push(t),
+            Some((c, _, t)) => starts[c].push(t + 1),
+            None => {}
+        }
+    }
+    let intervals = starts
+        .into_iter()
+        .map(|mut s| {
+            s.sort_unstable();
+            s.dedup();
+            s.into_iter().tuple_windows::<(usize, usize)>().collect_vec()
+        })
+        .collect_vec();
+
+    intervals
+        .iter()
+        .multi_cartesian_product()
+        .filter(|cell| reference_accepts(&workflows, [cell[0].0, cell[1].0, cell[2].0, cell[3].0]))
+        .map(|cell| cell.iter().map(|(lo, hi)| hi - lo).product::<usize>())
+        .sum()
+}
+
+/// The workflow tree, with edges labelled by their condition. Edges into the accept leaf are
+/// highlighted.
+pub fn draw_workflows(input: &str) -> Dot {
+    let (workflow_map, _) = parse(input);
+    let mut dot = Dot::directed();
+
+    for name in workflow_map.keys().sorted() {
+        dot.node(name, Shape::Box);
+    }
+    dot.node("A", Shape::DoubleCircle);
+    dot.fill("A", "palegreen");
+    dot.node("R", Shape::Octagon);
+    dot.fill("R", "lightcoral");
+
+    for (name, workflow) in workflow_map.iter().sorted_by_key(|(name, _)| *name) {
+        for c in &workflow.conditions {
+            dot.edge(name, c.result(), c.label().as_deref());
+        }
+        dot.highlight(name, "A");
+    }
+
+    dot
+}
@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::utils::parse::{split_label, ParseError};
+
+/// Cube counts by colour. Colours that aren't mentioned count as 0.
+pub type Cubes = BTreeMap<String, u32>;
+
+/// The bag part 1 asks about.
+pub const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// The cubes shown in one round of a game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Round {
+    pub cubes: Cubes,
+}
+
+/// A game with its ID as written in the input, which need not follow the line number.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle2::{bag, Game};
+///
+/// let game: Game = "Game 17: 3 blue, 4 teal; 1 red, 2 teal".parse().unwrap();
+/// assert_eq!(game.id, 17);
+/// assert_eq!(game.rounds.len(), 2);
+/// assert_eq!(game.minimum_set().cubes, bag(&[("blue", 3), ("red", 1), ("teal", 4)]));
+///
+/// assert!(game.is_game_possible(&bag(&[("red", 1), ("blue", 3), ("teal", 4)])));
+/// assert!(!game.is_game_possible(&bag(&[("red", 12), ("green", 13), ("blue", 14)])));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+/// Builds a bag specification from `(colour, count)` pairs.
+pub fn bag(counts: &[(&str, u32)]) -> Cubes {
+    counts.iter().map(|&(colour, n)| (colour.to_owned(), n)).collect()
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    /// Parses the `<count> <colour>` draws of a round, e.g. `3 blue, 4 red`. A colour drawn twice
+    /// in one round counts the sum of both draws.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cubes = Cubes::new();
+        for draw in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let (count, colour) = draw
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(draw, "expected `<count> <colour>`"))?;
+            let count: u32 = count
+                .parse()
+                .map_err(|e| ParseError::new(draw, format!("invalid count `{count}`: {e}")))?;
+            *cubes.entry(colour.trim().to_owned()).or_default() += count;
+        }
+
+        Ok(Round { cubes })
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, rounds) = split_label(s)?;
+        let id = label
+            .strip_prefix("Game")
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| ParseError::new(s, "expected `Game <id>:`"))?;
+        let rounds = rounds
+            .split(';')
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, rounds })
+    }
+}
+
+impl Game {
+    /// Whether every round could have been drawn from `bag`. Colours missing from the bag can't
+    /// be drawn at all.
+    pub fn is_game_possible(&self, bag: &Cubes) -> bool {
+        self.rounds
+            .iter()
+            .all(|r| r.cubes.iter().all(|(colour, &n)| n <= bag.get(colour).copied().unwrap_or(0)))
+    }
+
+    /// The fewest cubes of each colour that make the game possible.
+    pub fn minimum_set(&self) -> Round {
+        let mut cubes = Cubes::new();
+        for (colour, &n) in self.rounds.iter().flat_map(|r| &r.cubes) {
+            let min = cubes.entry(colour.clone()).or_default();
+            *min = (*min).max(n);
+        }
+
+        Round { cubes }
+    }
+}
+
+impl Round {
+    /// The chance of drawing exactly these cubes at once from `bag`, without putting any back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle2::{bag, Round};
+    ///
+    /// let round: Round = "1 red, 1 blue".parse().unwrap();
+    /// let p = round.probability(&bag(&[("red", 2), ("blue", 1)]));
+    /// assert!((p - 2.0 / 3.0).abs() < 1e-12);
+    /// assert_eq!(round.probability(&bag(&[("red", 2)])), 0.0);
+    ///
+    /// // More cubes than the bag holds.
+    /// let round: Round = "3 red".parse().unwrap();
+    /// assert_eq!(round.probability(&bag(&[("red", 2)])), 0.0);
+    /// ```
+    pub fn probability(&self, bag: &Cubes) -> f64 {
+        let total = bag.values().sum();
+        let drawn = self.cubes.values().sum();
+        if drawn > total {
+            return 0.0;
+        }
+        let ways: f64 = self
+            .cubes
+            .iter()
+            .map(|(colour, &n)| choose(bag.get(colour).copied().unwrap_or(0), n))
+            .product();
+
+        ways / choose(total, drawn)
+    }
+}
+
+fn choose(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k).map(|i| f64::from(n - i) / f64::from(i + 1)).product()
+}
+
+/// How far a game goes over a bag: for each colour, the most cubes of it shown in one round
+/// beyond what the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excess {
+    pub game: u32,
+    pub cubes: Cubes,
+}
+
+/// The smallest bag that every game could have been played with.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle2::{bag, minimal_bag, parse_games};
+///
+/// let games = parse_games("Game 1: 3 red; 1 blue\nGame 5: 2 red, 4 green").unwrap();
+/// assert_eq!(minimal_bag(&games), bag(&[("blue", 1), ("green", 4), ("red", 3)]));
+/// ```
+pub fn minimal_bag(games: &[Game]) -> Cubes {
+    let mut cubes = Cubes::new();
+    for (colour, n) in games.iter().flat_map(|g| g.minimum_set().cubes) {
+        let min = cubes.entry(colour).or_default();
+        *min = (*min).max(n);
+    }
+    cubes
+}
+
+/// The games that couldn't have been played with `bag`, in input order.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle2::{bag, parse_games, violations};
+///
+/// let games = parse_games("Game 1: 3 red; 1 blue\nGame 5: 2 red, 4 green").unwrap();
+/// let excesses = violations(&games, &bag(&[("red", 2), ("blue", 1), ("green", 1)]));
+/// assert_eq!(excesses.len(), 2);
+/// assert_eq!(excesses[0].cubes, bag(&[("red", 1)]));
+/// assert_eq!(excesses[1].game, 5);
+/// assert_eq!(excesses[1].cubes, bag(&[("green", 3)]));
+/// ```
+pub fn violations(games: &[Game], bag: &Cubes) -> Vec<Excess> {
+    games
+        .iter()
+        .filter_map(|g| {
+            let cubes: Cubes = g
+                .minimum_set()
+                .cubes
+                .into_iter()
+                .filter_map(|(colour, n)| {
+                    let over = n.saturating_sub(bag.get(&colour).copied().unwrap_or(0));
+                    (over > 0).then_some((colour, over))
+                })
+                .collect();
+            (!cubes.is_empty()).then_some(Excess { game: g.id, cubes })
+        })
+        .collect()
+}
+
+/// The [`probability`](Round::probability) of every round of every game under `bag`, by game ID.
+pub fn round_probabilities(games: &[Game], bag: &Cubes) -> Vec<(u32, Vec<f64>)> {
+    games
+        .iter()
+        .map(|g| (g.id, g.rounds.iter().map(|r| r.probability(bag)).collect()))
+        .collect()
+}
+
+/// Writes cube counts the way the input does, e.g. `3 blue, 4 red`.
+pub fn describe(cubes: &Cubes) -> String {
+    cubes.iter().map(|(colour, n)| format!("{n} {colour}")).join(", ")
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.at_line(i + 1)))
+        .collect()
+}
+
+fn games(input: &str) -> Vec<Game> {
+    parse_games(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn part1(input: &str) -> u32 {
+    let bag = bag(&BAG);
+    games(input)
+        .iter()
+        .filter(|g| g.is_game_possible(&bag))
+        .map(|g| g.id)
+        .sum()
+}
+
+pub fn part2(input: &str) -> u32 {
+    games(input)
+        .iter()
+        .map(|g| {
+            let min_set = g.minimum_set();
+
+            BAG.iter()
+                .map(|(colour, _)| min_set.cubes.get(*colour).copied().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum()
+}
+
+/// Every `<count> <colour>` draw of a line, read by splitting on whitespace.
+fn draws(line: &str) -> Vec<(u32, &str)> {
+    let (_, rounds) = line.split_once(':').unwrap();
+    let words: Vec<&str> = rounds
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    words.chunks(2).map(|pair| (pair[0].parse().unwrap(), pair[1])).collect()
+}
+
+/// Reference for [`part1`], checking each draw against the bag separately.
+pub fn reference_part1(input: &str) -> u32 {
+    input
+        .lines()
+        .filter(|line| {
+            draws(line).iter().all(|&(n, colour)| match colour {
+                "red" => n <= 12,
+                "green" => n <= 13,
+                "blue" => n <= 14,
+                _ => false,
+            })
+        })
+        .map(|line| line.split_once(':').unwrap().0["Game ".len()..].parse::<u32>().unwrap())
+        .sum()
+}
+
+/// Reference for [`part2`], taking the largest draw of each colour.
+pub fn reference_part2(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let d = draws(line);
+            ["red", "green", "blue"]
+                .iter()
+                .map(|c| d.iter().filter(|(_, colour)| colour == c).map(|(n, _)| *n).max().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum()
+}
@@ -0,0 +1,408 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Not;
+use itertools::Itertools;
+use crate::runner::Assumption;
+use crate::utils::cancel::{Cancel, Cancelled};
+use crate::utils::dot::{Dot, Shape};
+use crate::utils::lcm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pulse {
+    High,
+    Low
+}
+
+#[derive(Debug)]
+enum Module {
+    FlipFlop {
+        name: String,
+        state: bool,
+        outputs: Vec<String>
+    },
+    Conjunction {
+        name: String,
+        inputs: HashMap<String, Pulse>,
+        outputs: Vec<String>
+    },
+    Broadcast {
+        name: String,
+        outputs: Vec<String>
+    },
+    Button,
+    Output
+}
+
+impl Module {
+    fn from(name: &str, outputs: Vec<String>) -> Self {
+        match name {
+            "broadcaster" => Self::Broadcast { name: name.to_owned(), outputs },
+            s if s.starts_with('&') => Self::Conjunction {
+                name: s.trim_start_matches('&').to_string(),
+                inputs: HashMap::default(),
+                outputs,
+            },
+            s if s.starts_with('%') => Self::FlipFlop {
+                name: s.trim_start_matches('%').to_string(),
+                state: false,
+                outputs,
+            },
+            "output" => Self::Output,
+            _ => unreachable!()
+        }
+    }
+
+    fn name(&self) -> String {
+        match &self {
+            Module::FlipFlop { name, state: _, outputs: _ } => name.clone(),
+            Module::Conjunction { name, inputs: _, outputs: _ } => name.clone(),
+            Module::Broadcast { name, outputs: _ } => name.clone(),
+            Module::Button => "button".to_owned(),
+            Module::Output => "output".to_owned()
+        }
+    }
+
+    fn outputs(&self) -> &[String] {
+        match &self {
+            Module::FlipFlop { name: _, state: _, outputs } => outputs,
+            Module::Conjunction { name: _, inputs: _, outputs } => outputs,
+            Module::Broadcast { name: _, outputs } => outputs,
+            Module::Button | Module::Output => &[]
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        match &self {
+            Module::FlipFlop { .. } => Shape::Box,
+            Module::Conjunction { .. } => Shape::Diamond,
+            Module::Broadcast { .. } => Shape::DoubleCircle,
+            Module::Button => Shape::Point,
+            Module::Output => Shape::Octagon
+        }
+    }
+
+    fn process_pulse(&mut self, input: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        match self {
+            Module::FlipFlop { name: _, state, outputs } => {
+                if let Pulse::Low = pulse {
+                    *state = state.not();
+                    let output_pulse = if *state { Pulse::High } else { Pulse::Low };
+                    outputs.iter().map(|s| (s.clone(), output_pulse)).collect_vec()
+                } else {
+                    vec![]
+                }
+            }
+            Module::Conjunction { name: _, inputs, outputs } => {
+                inputs.insert(input.to_owned(), pulse);
+                if inputs.values().all(|v| v == &Pulse::High) {
+                    outputs.iter().map(|s| (s.clone(), Pulse::Low)).collect_vec()
+                } else {
+                    outputs.iter().map(|s| (s.clone(), Pulse::High)).collect_vec()
+
+                }
+            }
+            Module::Broadcast { name: _, outputs } => {
+                outputs.iter().map(|s| (s.clone(), pulse)).collect_vec()
+            }
+            Module::Button => vec![("broadcaster".to_owned(), Pulse::Low)],
+            Module::Output => vec![]
+        }
+    }
+}
+
+fn parse(input: &str) -> HashMap<String, Module> {
+    let lines = input.lines().collect_vec();
+
+    let mut modules = HashMap::new();
+    let mut name_to_outputs: HashMap<String, Vec<String>> = HashMap::new();
+
+    for line in &lines {
+        let (name, outputs) = line.split("->").map(str::trim).collect_tuple().unwrap();
+        let outputs = outputs.split(',').map(str::trim).map(ToOwned::to_owned).collect_vec();
+        let module = Module::from(name, outputs.clone());
+        name_to_outputs.insert(module.name(), outputs.clone());
+        modules.insert(module.name(), module);
+    }
+
+    for (name, outputs) in &name_to_outputs {
+        for s in outputs {
+            let module = modules.get_mut(s.as_str());
+            if let Some(Module::Conjunction { name: _, inputs, outputs: _ }) = module {
+                inputs.insert(name.clone(), Pulse::Low);
+            }
+        }
+    }
+
+    modules.insert("button".to_owned(), Module::Button);
+    modules
+}
+
+/// Pushes the button once, calling `on_pulse` with `(from, to, pulse)` for every pulse sent.
+fn press(modules: &mut HashMap<String, Module>, mut on_pulse: impl FnMut(&str, &str, Pulse)) {
+    let mut current_pulses: Vec<(String, String, Pulse)> = vec![("button".to_owned(), "broadcaster".to_owned(), Pulse::Low)];
+    while !current_pulses.is_empty() {
+        let mut next_pulses: Vec<(String, String, Pulse)> = vec![];
+        for (i, o, p) in &current_pulses {
+            on_pulse(i, o, *p);
+            if o == "output" {
+                continue
+            }
+            let module = modules.get_mut(o);
+            if let Some(module) = module {
+                let outputs = module.process_pulse(i, *p).into_iter().map(|(m, pulse)| (o.clone(), m, pulse)).collect_vec();
+                next_pulses.extend_from_slice(&outputs[..]);
+            }
+        }
+        current_pulses = next_pulses;
+    }
+}
+
+pub fn part1(input: &str) -> u64 {
+    let mut modules = parse(input);
+    let (mut low_count, mut high_count) = (0, 0);
+
+    for _ in 0..1000 {
+        press(&mut modules, |_, _, p| match p {
+            Pulse::High => high_count += 1,
+            Pulse::Low => low_count += 1,
+        });
+    }
+
+    low_count * high_count
+}
+
+/// The names of the modules sending pulses to `target`.
+fn feeders_of(modules: &HashMap<String, Module>, target: &str) -> Vec<String> {
+    modules
+        .iter()
+        .filter(|(_, m)| m.outputs().iter().any(|o| o == target))
+        .map(|(name, _)| name.clone())
+        .sorted()
+        .collect_vec()
+}
+
+/// Like the real inputs, `rx` is fed by a single conjunction whose inputs each send a high pulse
+/// on a fixed cycle, so `rx` first sees a low pulse at the LCM of those cycles. Returns 0 when
+/// nothing feeds `rx`.
+pub fn part2(input: &str) -> u64 {
+    part2_until(input, &Cancel::never()).unwrap()
+}
+
+/// [`part2`], giving up once `cancel` says so. The partial answer is the LCM of the cycles found
+/// so far, which divides the real answer.
+pub fn part2_until(input: &str, cancel: &Cancel) -> Result<u64, Cancelled<u64>> {
+    let mut modules = parse(input);
+    let Some(hub) = feeders_of(&modules, "rx").pop() else {
+        return Ok(0);
+    };
+    let watched = feeders_of(&modules, &hub);
+
+    let cycles = |presses: HashMap<String, Vec<u64>>| {
+        presses
+            .into_values()
+            .filter_map(|p| match p[..] {
+                [first, second] => Some(second - first),
+                _ => None,
+            })
+            .collect_vec()
+    };
+    // Only `cancel` stops the presses, so on success every watched input has cycled.
+    match high_pulse_presses(&mut modules, &hub, &watched, None, cancel) {
+        Ok(presses) => Ok(lcm(&cycles(presses))),
+        Err(presses) => {
+            let found = cycles(presses);
+            Err(Cancelled {
+                partial: (!found.is_empty()).then(|| lcm(&found)),
+            })
+        }
+    }
+}
+
+/// How many presses the assumptions wait for a cycle to show up.
+const PRESS_LIMIT: u64 = 100_000;
+
+/// Presses the button until each of `watched` has sent `hub` a high pulse twice, or `limit`
+/// times if there is one. Returns the presses on which each of them did, as an error if `cancel`
+/// stopped it first.
+fn high_pulse_presses(
+    modules: &mut HashMap<String, Module>,
+    hub: &str,
+    watched: &[String],
+    limit: Option<u64>,
+    cancel: &Cancel,
+) -> Result<HashMap<String, Vec<u64>>, HashMap<String, Vec<u64>>> {
+    let mut presses: HashMap<String, Vec<u64>> = HashMap::new();
+
+    for count in 1..=limit.unwrap_or(u64::MAX) {
+        press(modules, |i, o, p| {
+            if o == hub && p == Pulse::High {
+                let seen = presses.entry(i.to_owned()).or_default();
+                if seen.len() < 2 && seen.last() != Some(&count) {
+                    seen.push(count);
+                }
+            }
+        });
+        if watched.iter().all(|w| presses.get(w).is_some_and(|p| p.len() == 2)) {
+            break;
+        }
+        if cancel.is_cancelled() {
+            return Err(presses);
+        }
+    }
+
+    Ok(presses)
+}
+
+/// Part 2 only watches the inputs of the conjunction in front of `rx`, and multiplies out their
+/// cycles as if each started on the first press.
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        part: 2,
+        description: "`rx` is fed by a single conjunction",
+        check: check_hub,
+    },
+    Assumption {
+        part: 2,
+        description: "each input of that conjunction sends it a high pulse every n presses, starting on press n",
+        check: check_cycles,
+    },
+];
+
+fn check_hub(input: &str) -> Result<(), String> {
+    let modules = parse(input);
+    match &feeders_of(&modules, "rx")[..] {
+        [] => Err("nothing sends pulses to `rx`".to_owned()),
+        [hub] => match modules.get(hub) {
+            Some(Module::Conjunction { .. }) => Ok(()),
+            _ => Err(format!("`{hub}` is not a conjunction")),
+        },
+        feeders => Err(format!("`rx` is fed by {}", feeders.join(", "))),
+    }
+}
+
+fn check_cycles(input: &str) -> Result<(), String> {
+    // Without a single conjunction in front of `rx` there are no inputs to watch, and
+    // `check_hub` already says so.
+    if check_hub(input).is_err() {
+        return Ok(());
+    }
+    let mut modules = parse(input);
+    let hub = feeders_of(&modules, "rx").remove(0);
+    let watched = feeders_of(&modules, &hub);
+    let Ok(presses) = high_pulse_presses(&mut modules, &hub, &watched, Some(PRESS_LIMIT), &Cancel::never()) else {
+        unreachable!()
+    };
+
+    for name in &watched {
+        match presses.get(name).map(Vec::as_slice) {
+            Some(&[first, second]) if second == 2 * first => {}
+            Some(&[first, second]) => {
+                return Err(format!("`{name}` sends high pulses on presses {first} and {second}"));
+            }
+            _ => {
+                return Err(format!("`{name}` does not send two high pulses in {PRESS_LIMIT} presses"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reference for [`part1`]: the same 1000 presses, but with a single FIFO queue of pulses.
+pub fn reference_part1(input: &str) -> u64 {
+    reference_presses(input, Some(1000)).0
+}
+
+/// Reference for [`part2`], pressing the button until `rx` receives a low pulse.
+pub fn reference_part2(input: &str) -> u64 {
+    if !input.contains("-> rx") && !input.contains(", rx") {
+        return 0;
+    }
+    reference_presses(input, None).1
+}
+
+/// Presses the button `limit` times, or until `rx` gets a low pulse when there is no limit.
+/// Returns the product of the pulse counts and the number of presses.
+fn reference_presses(input: &str, limit: Option<u64>) -> (u64, u64) {
+    let mut kinds = HashMap::new();
+    let mut outputs = HashMap::new();
+    for line in input.lines() {
+        let (module, targets) = line.split_once(" -> ").unwrap();
+        let name = module.trim_start_matches(['%', '&']);
+        kinds.insert(name, module.chars().next().unwrap());
+        outputs.insert(name, targets.split(", ").collect_vec());
+    }
+
+    let mut on: HashMap<&str, bool> = HashMap::new();
+    let mut memory: HashMap<(&str, &str), bool> = HashMap::new();
+    for (&from, targets) in &outputs {
+        for &to in targets {
+            memory.insert((to, from), false);
+        }
+    }
+
+    let (mut low, mut high, mut presses) = (0, 0, 0);
+    loop {
+        if limit == Some(presses) {
+            return (low * high, presses);
+        }
+        presses += 1;
+
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, is_high)) = queue.pop_front() {
+            if is_high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+            if to == "rx" && !is_high && limit.is_none() {
+                return (low * high, presses);
+            }
+
+            let sent = match kinds.get(to) {
+                Some('%') if !is_high => {
+                    let state = on.entry(to).or_insert(false);
+                    *state = !*state;
+                    *state
+                }
+                Some('&') => {
+                    memory.insert((to, from), is_high);
+                    !memory.iter().filter(|((m, _), _)| *m == to).all(|(_, &h)| h)
+                }
+                Some('b') => is_high,
+                _ => continue,
+            };
+            for &next in &outputs[to] {
+                queue.push_back((to, next, sent));
+            }
+        }
+    }
+}
+
+/// The module wiring, shaped by module type. Edges into `rx` and into the modules feeding it
+/// are highlighted, which shows the conjunction whose inputs part 2 has to cycle-detect.
+pub fn draw_modules(input: &str) -> Dot {
+    let modules = parse(input);
+    let mut dot = Dot::directed();
+
+    for (name, module) in modules.iter().sorted_by_key(|(name, _)| *name) {
+        dot.node(name, module.shape());
+    }
+
+    for (name, module) in modules.iter().sorted_by_key(|(name, _)| *name) {
+        if let Module::Button = module {
+            dot.edge(name, "broadcaster", None);
+        }
+        for output in module.outputs() {
+            dot.edge(name, output, None);
+        }
+    }
+
+    for feeder in feeders_of(&modules, "rx") {
+        dot.highlight(&feeder, "rx");
+        for input in feeders_of(&modules, &feeder) {
+            dot.highlight(&input, &feeder);
+        }
+    }
+
+    dot
+}
@@ -0,0 +1,288 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+use crate::runner::Assumption;
+use crate::utils::anim::Animator;
+use crate::utils::image::{Image, Rgb};
+use crate::utils::render::GridRenderer;
+
+pub const PART2_STEPS: usize = 26501365;
+
+/// The garden shape the part 2 extrapolation relies on: the walk spreads as a diamond that reaches
+/// each neighbouring copy of the garden at the same time, through its centre lines and border.
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        part: 2,
+        description: "the garden is a square with an odd side",
+        check: check_square,
+    },
+    Assumption {
+        part: 2,
+        description: "`S` is in the centre of the garden",
+        check: check_centred,
+    },
+    Assumption {
+        part: 2,
+        description: "the row and column through `S` and the border are free of rocks",
+        check: check_lanes,
+    },
+];
+
+fn check_square(input: &str) -> Result<(), String> {
+    let (grid, _) = parse(input);
+    let side = grid.len();
+    if let Some((i, row)) = grid.iter().enumerate().find(|(_, row)| row.len() != side) {
+        return Err(format!("row {i} has {} plots in a garden of {side} rows", row.len()));
+    }
+    if side.is_multiple_of(2) {
+        return Err(format!("the side is {side}"));
+    }
+    Ok(())
+}
+
+fn check_centred(input: &str) -> Result<(), String> {
+    let (grid, starting_position) = parse(input);
+    let centre = (grid.len() / 2, grid[0].len() / 2);
+    if starting_position != centre {
+        return Err(format!("`S` is at {starting_position:?} instead of {centre:?}"));
+    }
+    Ok(())
+}
+
+fn check_lanes(input: &str) -> Result<(), String> {
+    let (grid, (sx, sy)) = parse(input);
+    let (max_x, max_y) = (grid.len() - 1, grid[0].len() - 1);
+    let rock = (0..=max_x)
+        .flat_map(|i| (0..=max_y).map(move |j| (i, j)))
+        .filter(|&(i, j)| i == sx || j == sy || i == 0 || j == 0 || i == max_x || j == max_y)
+        .find(|&(i, j)| grid[i][j] == '#');
+    match rock {
+        Some(rock) => Err(format!("there is a rock at {rock:?}")),
+        None => Ok(()),
+    }
+}
+
+fn parse(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
+    let grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+
+    let mut starting_position = (0, 0);
+    for (i, row) in grid.iter().enumerate() {
+        for (j, c) in row.iter().enumerate() {
+            if c == &'S' {
+                starting_position = (i, j);
+            }
+        }
+    }
+
+    (grid, starting_position)
+}
+
+pub fn part1(input: &str) -> usize {
+    let (grid, starting_position) = parse(input);
+    bfs(&grid, starting_position, 64).len()
+}
+
+pub fn part2(input: &str) -> u64 {
+    reachable_after(input, PART2_STEPS)
+}
+
+/*
+ The number of plots reachable after `steps % side + n * side` steps is a quadratic in `n`
+ (for the real input: 3882, 34441, 95442, ...). Get the first 3 terms of the sequence by
+ running bfs unbounded, then use the formula for the nth term of a quadratic sequence.
+ */
+pub fn reachable_after(input: &str, steps: usize) -> u64 {
+    let (grid, starting_position) = parse(input);
+    let side = grid.len();
+    let offset = steps % side;
+
+    let terms = (0..3)
+        .map(|n| bfs_unbounded(&grid, starting_position, offset + n * side) as i64)
+        .collect_vec();
+    fofn((steps / side) as i64, &terms) as u64
+}
+
+/// Reference for [`reachable_after`]: a BFS over the garden repeated in every direction, counting
+/// the plots at most `steps` away with the same parity as `steps`.
+pub fn reference_reachable(input: &str, steps: usize) -> u64 {
+    let (grid, (sx, sy)) = parse(input);
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+    let start = (sx as isize, sy as isize);
+
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some((x, y)) = queue.pop_front() {
+        let d = distances[&(x, y)];
+        if d == steps {
+            continue;
+        }
+        for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if grid[nx.rem_euclid(rows) as usize][ny.rem_euclid(cols) as usize] != '#' {
+                distances.entry((nx, ny)).or_insert_with(|| {
+                    queue.push_back((nx, ny));
+                    d + 1
+                });
+            }
+        }
+    }
+
+    distances.values().filter(|&&d| d % 2 == steps % 2).count() as u64
+}
+
+/// Reference for [`part1`], stepping every reachable plot with a set.
+pub fn reference_part1(input: &str) -> usize {
+    let (grid, start) = parse(input);
+
+    let mut reachable = HashSet::from([start]);
+    for _ in 0..64 {
+        reachable = reachable
+            .iter()
+            .flat_map(|&(x, y)| [(x + 1, y), (x.wrapping_sub(1), y), (x, y + 1), (x, y.wrapping_sub(1))])
+            .filter(|&(x, y)| grid.get(x).and_then(|row| row.get(y)).is_some_and(|&c| c != '#'))
+            .collect();
+    }
+    reachable.len()
+}
+
+pub fn animate_steps(input: &str) {
+    let (grid, starting_position) = parse(input);
+    let grid = &grid;
+    let max_steps = 64;
+    let mut current_nodes = vec![starting_position];
+
+    Animator::new()
+        .interactive(true)
+        .run(|step| {
+            if step > max_steps || current_nodes.is_empty() {
+                return None;
+            }
+            let frame = GridRenderer::new()
+                .overlay(current_nodes.iter().copied(), 'O')
+                .render_to_string(grid);
+            current_nodes = step_plots(grid, &current_nodes);
+            Some(frame)
+        })
+        .unwrap();
+}
+
+/// The garden with the plots reachable in exactly 64 steps marked.
+pub fn draw_plots(input: &str) -> Image {
+    let (grid, starting_position) = parse(input);
+    let mut image = Image::from_grid(&grid, |c| if c == &'#' { Rgb::GRAY } else { Rgb::WHITE });
+    for (i, j) in bfs(&grid, starting_position, 64) {
+        image.set(i, j, Rgb::GREEN);
+    }
+    image
+}
+
+fn bfs(grid: &[Vec<char>], starting_position: (usize, usize), max_steps: usize) -> Vec<(usize, usize)> {
+    let mut current_nodes = vec![starting_position];
+    let mut step_count = 0;
+
+    while !current_nodes.is_empty() && step_count < max_steps {
+        current_nodes = step_plots(grid, &current_nodes);
+        // println!("At step {step_count} found nodes: {}", current_nodes.len());
+        step_count += 1;
+    }
+
+    current_nodes
+}
+
+/// Returns the garden plots reachable in exactly one more step from any of `current_nodes`.
+fn step_plots(grid: &[Vec<char>], current_nodes: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut next_nodes = vec![];
+
+    for position in current_nodes {
+        for neighbor in iter_neighbors4_bounded_usize(position.0, position.1, max_x, max_y) {
+            if grid[neighbor.0][neighbor.1] == '#' {
+                continue;
+            }
+            if visited.insert(neighbor) {
+                next_nodes.push(neighbor);
+            }
+        }
+    }
+
+    next_nodes
+}
+
+fn bfs_unbounded(grid: &[Vec<char>], starting_position: (usize, usize), max_steps: usize) -> usize {
+    let max_x = grid.len();
+    let max_y = grid[0].len();
+
+    let mut current_nodes = vec![((starting_position.0 as isize, starting_position.1 as isize), 0)];
+    let mut step_count = 1;
+
+    let mut visited_steps: HashSet<((isize, isize), usize)> = HashSet::new();
+
+    while !current_nodes.is_empty() && step_count <= max_steps {
+        let mut next_nodes = vec![];
+
+        for (position, step) in &current_nodes {
+            for neighbor in iter_neighbors4_unbounded(position.0, position.1) {
+                let mut translated_x = (neighbor.0.abs() % max_x as isize) as usize;
+                let mut translated_y = (neighbor.1.abs() % max_y as isize) as usize;
+                if neighbor.0 < 0 && translated_x != 0 {
+                    translated_x = max_x - translated_x;
+                }
+                if neighbor.1 < 0 && translated_y != 0 {
+                    translated_y = max_y - translated_y;
+                }
+
+                if grid[translated_x][translated_y] == '#' {
+                    continue;
+                }
+
+                if visited_steps.contains(&(neighbor, step + 1)) {
+                    continue;
+                }
+                next_nodes.push((neighbor, step + 1));
+                visited_steps.insert((neighbor, step + 1));
+            }
+        }
+
+        current_nodes = next_nodes;
+        step_count += 1;
+    }
+
+    current_nodes.len()
+}
+
+fn fofn(n: i64, terms: &[i64]) -> i64 {
+    let (a0, a1, a2) = (terms[0], terms[1], terms[2]);
+
+    let b0 = a0;
+    let b1 = a1 - a0;
+    let b2 = a2 - a1;
+
+    b0 + b1 * n + (n * (n - 1) / 2) * (b2 - b1)
+}
+
+
+pub fn iter_neighbors4_bounded_usize(x: usize, y: usize, max_x: usize, max_y: usize) -> Vec<(usize, usize)> {
+    [
+        (x, y + 1),
+        (x, y.wrapping_sub(1)),
+        (x.wrapping_sub(1), y),
+        (x + 1, y),
+    ]
+        .into_iter()
+        .filter(|(nx, ny)| *nx < max_x && *ny < max_y)
+        .unique()
+        .collect_vec()
+}
+
+pub fn iter_neighbors4_unbounded(x: isize, y: isize) -> Vec<(isize, isize)> {
+    [
+        (x, y + 1),
+        (x, y - 1),
+        (x - 1, y),
+        (x + 1, y),
+    ]
+        .into_iter()
+        .collect_vec()
+}
\ No newline at end of file
@@ -0,0 +1,331 @@
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use crate::utils::svg::Svg;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+struct Coord2 {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+struct Coord {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Coord {
+    fn xy(&self) -> Coord2 {
+        Coord2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl From<&str> for Coord {
+    fn from(value: &str) -> Self {
+        let (x, y, z) = value
+            .split(',')
+            .map(|c| c.parse().unwrap())
+            .collect_tuple()
+            .unwrap();
+        Coord { x, y, z }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+struct Brick {
+    start: Coord,
+    end: Coord,
+}
+
+impl Brick {
+    fn get_all_xy_coords(&self) -> Vec<Coord2> {
+        let mut result = vec![];
+        let start_xy = self.start.xy();
+        let end_xy = self.end.xy();
+        let (low_y, high_y) = (min(start_xy.y, end_xy.y), max(start_xy.y, end_xy.y));
+        let (low_x, high_x) = (min(start_xy.x, end_xy.x), max(start_xy.x, end_xy.x));
+        for x in low_x..=high_x {
+            for y in low_y..=high_y {
+                result.push(Coord2 { x, y });
+            }
+        }
+        result
+    }
+}
+
+impl From<&str> for Brick {
+    fn from(value: &str) -> Self {
+        let (start, end) = value.split('~').collect_tuple().unwrap();
+        Brick {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
+
+/// The settled bricks, how many could be safely removed and how many would fall in total.
+fn scan(input: &str) -> (Vec<Brick>, i32, i32) {
+    let lines = input.lines().collect_vec();
+
+    let mut bricks: Vec<Brick> = lines.into_iter().map(Into::into).collect_vec();
+
+    bricks.sort_by_key(|b| b.start.z);
+
+    let max_x = bricks
+        .iter()
+        .map(|b| max(b.start.x, b.end.x))
+        .max()
+        .unwrap();
+    let max_y = bricks
+        .iter()
+        .map(|b| max(b.start.y, b.end.y))
+        .max()
+        .unwrap();
+
+    let (collapsed_bricks, _) = drop_bricks(&bricks, max_x, max_y);
+
+    let mut collapse_count_sum = 0;
+    let mut no_collapse_count_sum = 0;
+    for b in &collapsed_bricks {
+        let mut new_bricks = collapsed_bricks.clone();
+        new_bricks.remove(new_bricks.iter().position(|x| x == b).unwrap());
+
+        let (_, collapse_count) = drop_bricks(&new_bricks, max_x, max_y);
+        collapse_count_sum += collapse_count;
+        if collapse_count == 0 {
+            no_collapse_count_sum += 1;
+        }
+    }
+
+    (collapsed_bricks, no_collapse_count_sum, collapse_count_sum)
+}
+
+pub fn part1(input: &str) -> i32 {
+    scan(input).1
+}
+
+pub fn part2(input: &str) -> i32 {
+    scan(input).2
+}
+
+/// The lowest and highest cells of a brick.
+type Corners = ((usize, usize, usize), (usize, usize, usize));
+
+/// The settled bricks as their lowest and highest cells, ordered so that painting them in turn
+/// in isometric projection leaves every brick hidden behind the ones in front of it. The viewer
+/// looks down from high `x`, `y` and `z`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle22::paint_order;
+///
+/// // A short stack behind a tall brick has to be painted first, however high it reaches.
+/// let order = paint_order("1,0,1~1,0,9\n0,0,1~0,0,3\n0,0,4~0,0,4\n");
+/// assert_eq!(order, [((0, 0, 1), (0, 0, 3)), ((0, 0, 4), (0, 0, 4)), ((1, 0, 1), (1, 0, 9))]);
+/// ```
+pub fn paint_order(input: &str) -> Vec<Corners> {
+    let bricks = scan(input)
+        .0
+        .iter()
+        .map(|b| {
+            let low = (min(b.start.x, b.end.x), min(b.start.y, b.end.y), min(b.start.z, b.end.z));
+            let high = (max(b.start.x, b.end.x), max(b.start.y, b.end.y), max(b.start.z, b.end.z));
+            (low, high)
+        })
+        .collect_vec();
+
+    // Every brick painted after the ones it is in front of, breaking ties from the back.
+    let mut behind = vec![0; bricks.len()];
+    let mut in_front = vec![vec![]; bricks.len()];
+    for (a, b) in (0..bricks.len()).tuple_combinations() {
+        if hides(bricks[a], bricks[b]) {
+            in_front[b].push(a);
+            behind[a] += 1;
+        } else if hides(bricks[b], bricks[a]) {
+            in_front[a].push(b);
+            behind[b] += 1;
+        }
+    }
+
+    let depth = |i: usize| {
+        let (low, high) = bricks[i];
+        (low.0 + low.1 + low.2, high.0 + high.1 + high.2)
+    };
+    let mut ready = (0..bricks.len()).filter(|&i| behind[i] == 0).collect_vec();
+    let mut placed = vec![false; bricks.len()];
+    let mut order = vec![];
+    while order.len() < bricks.len() {
+        // Bricks can hide each other in a ring, and then no order is right. The ring is broken at
+        // the brick furthest back.
+        let next = match ready.iter().copied().min_by_key(|&i| (depth(i), i)) {
+            Some(i) => i,
+            None => (0..bricks.len()).filter(|&i| !placed[i]).min_by_key(|&i| (depth(i), i)).unwrap(),
+        };
+        ready.retain(|&r| r != next);
+        placed[next] = true;
+        order.push(bricks[next]);
+        for &j in &in_front[next] {
+            behind[j] -= 1;
+            if behind[j] == 0 && !placed[j] {
+                ready.push(j);
+            }
+        }
+    }
+
+    order
+}
+
+/// Whether brick `front` covers part of brick `back` in the picture. Their outlines overlap when
+/// they overlap along each of the three directions of the outlines' edges, and of two bricks that
+/// don't intersect, the one behind is the one below the other along some axis.
+fn hides(front: Corners, back: Corners) -> bool {
+    let axes = |((lx, ly, lz), (hx, hy, hz)): Corners| {
+        // Cells are a unit wide, so a brick spans `low..high + 1` along each axis.
+        let (lo, hi) = ([lx, ly, lz].map(|c| c as isize), [hx, hy, hz].map(|c| c as isize + 1));
+        (lo, hi)
+    };
+    let ((flo, fhi), (blo, bhi)) = (axes(front), axes(back));
+
+    let outlines_overlap = [(0, 1), (1, 2), (0, 2)].iter().all(|&(i, j)| {
+        let (f, b) = ((flo[i] - fhi[j], fhi[i] - flo[j]), (blo[i] - bhi[j], bhi[i] - blo[j]));
+        f.0 < b.1 && b.0 < f.1
+    });
+    outlines_overlap && (0..3).any(|k| bhi[k] <= flo[k])
+}
+
+/// The settled bricks in isometric projection.
+pub fn draw_bricks(input: &str) -> Svg {
+    let mut svg = Svg::new();
+    for (i, (low, high)) in paint_order(input).into_iter().enumerate() {
+        let hue = (i * 47) % 360;
+        svg.iso_box(
+            (low.0 as f64, low.1 as f64, low.2 as f64),
+            ((high.0 + 1) as f64, (high.1 + 1) as f64, (high.2 + 1) as f64),
+            &format!("hsl({hue}, 60%, 60%)"),
+        );
+    }
+
+    svg
+}
+
+fn drop_bricks(bricks: &Vec<Brick>, max_x: usize, max_y: usize) -> (Vec<Brick>, i32) {
+    let mut collapsed_bricks = vec![];
+    let mut collapsed_count = 0;
+    let mut tallest_so_far_map: HashMap<Coord2, usize> = HashMap::new();
+
+    for i in 0..=max_x {
+        for j in 0..=max_y {
+            tallest_so_far_map.insert(Coord2 { x: i, y: j }, 0);
+        }
+    }
+
+    for b in bricks {
+        let xy_coords = b.get_all_xy_coords();
+        let tallest_z_so_far = xy_coords
+            .iter()
+            .filter_map(|xy| tallest_so_far_map.get(xy).copied())
+            .max()
+            .unwrap();
+        let new_z_delta = min(b.start.z, b.end.z) - tallest_z_so_far - 1;
+        let collapsed_brick = Brick {
+            start: Coord {
+                z: b.start.z.wrapping_sub(new_z_delta),
+                ..b.start
+            },
+            end: Coord {
+                z: b.end.z.wrapping_sub(new_z_delta),
+                ..b.end
+            },
+        };
+        if collapsed_brick.start.z != b.start.z {
+            collapsed_count += 1;
+        }
+        for xy in &xy_coords {
+            tallest_so_far_map.insert(*xy, max(collapsed_brick.start.z, collapsed_brick.end.z));
+        }
+        collapsed_bricks.push(collapsed_brick);
+    }
+    (collapsed_bricks, collapsed_count)
+}
+
+type Cube = (usize, usize, usize);
+
+/// Every cube of every brick in the snapshot.
+fn reference_bricks(input: &str) -> Vec<Vec<Cube>> {
+    input
+        .lines()
+        .map(|line| {
+            let (a, b): (Coord, Coord) = line.split('~').map(Coord::from).collect_tuple().unwrap();
+            let mut cubes = vec![];
+            for x in a.x.min(b.x)..=a.x.max(b.x) {
+                for y in a.y.min(b.y)..=a.y.max(b.y) {
+                    for z in a.z.min(b.z)..=a.z.max(b.z) {
+                        cubes.push((x, y, z));
+                    }
+                }
+            }
+            cubes
+        })
+        .collect()
+}
+
+/// Lowers bricks one cube at a time until none can fall. Returns which bricks moved.
+fn reference_settle(bricks: &mut [Vec<Cube>]) -> HashSet<usize> {
+    let mut moved = HashSet::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let occupied: HashMap<Cube, usize> = bricks
+            .iter()
+            .enumerate()
+            .flat_map(|(i, cubes)| cubes.iter().map(move |&c| (c, i)))
+            .collect();
+
+        for (i, cubes) in bricks.iter_mut().enumerate() {
+            let blocked = cubes.iter().any(|&(x, y, z)| {
+                z == 1 || occupied.get(&(x, y, z - 1)).is_some_and(|&other| other != i)
+            });
+            if !blocked {
+                for cube in cubes.iter_mut() {
+                    cube.2 -= 1;
+                }
+                moved.insert(i);
+                changed = true;
+                // Other bricks must see the new position, so start a fresh pass.
+                break;
+            }
+        }
+    }
+    moved
+}
+
+/// How many bricks would fall if each brick were removed in turn.
+fn reference_falls(input: &str) -> Vec<usize> {
+    let mut bricks = reference_bricks(input);
+    reference_settle(&mut bricks);
+
+    (0..bricks.len())
+        .map(|i| {
+            let mut rest = bricks.clone();
+            rest.remove(i);
+            reference_settle(&mut rest).len()
+        })
+        .collect()
+}
+
+/// Reference for [`part1`], dropping the bricks one cube at a time.
+pub fn reference_part1(input: &str) -> i32 {
+    reference_falls(input).iter().filter(|&&n| n == 0).count() as i32
+}
+
+/// Reference for [`part2`], dropping the bricks one cube at a time.
+pub fn reference_part2(input: &str) -> i32 {
+    reference_falls(input).iter().sum::<usize>() as i32
+}
@@ -0,0 +1,303 @@
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+use crate::utils::anim::Animator;
+use crate::utils::cancel::{Cancel, Cancelled};
+use crate::utils::dot::{Dot, Shape};
+use crate::utils::render::{Color, GridRenderer};
+
+type Pos = (usize, usize);
+
+#[derive(Debug, Eq, PartialEq)]
+enum Direction {
+    Right,
+    Left,
+    Up,
+    Down
+}
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect_vec()).collect_vec()
+}
+
+pub fn part1(input: &str) -> usize {
+    part1_until(input, &Cancel::never()).unwrap()
+}
+
+/// Extremely slow on the real input.
+pub fn part2(input: &str) -> usize {
+    part2_until(input, &Cancel::never()).unwrap()
+}
+
+/// [`part1`], giving up with the longest hike found so far once `cancel` says so.
+pub fn part1_until(input: &str, cancel: &Cancel) -> Result<usize, Cancelled<usize>> {
+    dfs(&parse(input), true, cancel)
+}
+
+/// [`part2`], giving up with the longest hike found so far once `cancel` says so.
+pub fn part2_until(input: &str, cancel: &Cancel) -> Result<usize, Cancelled<usize>> {
+    dfs(&parse(input), false, cancel)
+}
+
+/// Longest hike from `(x, y)` to the bottom row, walking one cell at a time.
+fn reference_hike(grid: &[Vec<char>], (x, y): Pos, slopes: bool, visited: &mut HashSet<Pos>) -> Option<usize> {
+    if x == grid.len() - 1 {
+        return Some(0);
+    }
+
+    let forced = match grid[x][y] {
+        '>' if slopes => Some((x, y + 1)),
+        '<' if slopes => Some((x, y.wrapping_sub(1))),
+        'v' if slopes => Some((x + 1, y)),
+        '^' if slopes => Some((x.wrapping_sub(1), y)),
+        _ => None,
+    };
+    let steps = match forced {
+        Some(next) => vec![next],
+        None => vec![(x, y + 1), (x, y.wrapping_sub(1)), (x + 1, y), (x.wrapping_sub(1), y)],
+    };
+
+    let mut best = None;
+    for next in steps {
+        if next.0 >= grid.len() || next.1 >= grid[0].len() || grid[next.0][next.1] == '#' || !visited.insert(next) {
+            continue;
+        }
+        if let Some(length) = reference_hike(grid, next, slopes, visited) {
+            best = best.max(Some(length + 1));
+        }
+        visited.remove(&next);
+    }
+    best
+}
+
+fn reference_longest(input: &str, slopes: bool) -> usize {
+    let grid = parse(input);
+    let start = (0, grid[0].iter().position(|&c| c == '.').unwrap());
+    reference_hike(&grid, start, slopes, &mut HashSet::from([start])).unwrap_or(0)
+}
+
+/// Reference for [`part1`], a recursive walk that leaves a slope only downhill.
+pub fn reference_part1(input: &str) -> usize {
+    reference_longest(input, true)
+}
+
+/// Reference for [`part2`], a recursive walk over every cell.
+pub fn reference_part2(input: &str) -> usize {
+    reference_longest(input, false)
+}
+
+pub fn animate_search(input: &str) {
+    let grid = &parse(input);
+    let mut search = HikeSearch::new(grid, true);
+
+    Animator::new()
+        .interactive(true)
+        .run(|_| {
+            let (_, path) = search.current_nodes.front()?;
+            let frame = GridRenderer::new()
+                .highlight(search.max_path.iter().copied(), Color::Green)
+                .overlay_colored(path.iter().copied(), 'O', Color::Yellow)
+                .ansi(true)
+                .render_to_string(grid);
+            search.step();
+            Some(frame)
+        })
+        .unwrap();
+}
+
+/// The trail map compressed to its junctions, with edges labelled by trail length and the
+/// longest hike (ignoring slopes) highlighted.
+pub fn draw_trails(input: &str) -> Dot {
+    let (junctions, edges) = compress(&parse(input));
+    let id = |i: usize| format!("{},{}", junctions[i].0, junctions[i].1);
+    let mut dot = Dot::undirected();
+
+    dot.node(&id(0), Shape::DoubleCircle);
+    dot.node(&id(1), Shape::DoubleCircle);
+    for &(a, b, length) in &edges {
+        dot.edge(&id(a), &id(b), Some(&length.to_string()));
+    }
+
+    let mut adjacent = vec![vec![]; junctions.len()];
+    for &(a, b, length) in &edges {
+        adjacent[a].push((b, length));
+        adjacent[b].push((a, length));
+    }
+    let mut visited = vec![false; junctions.len()];
+    if let Some((_, path)) = longest_path(&adjacent, 0, 1, &mut visited) {
+        for (a, b) in path.into_iter().tuple_windows() {
+            dot.highlight(&id(a), &id(b));
+        }
+    }
+
+    dot
+}
+
+/// Finds the junctions of the trail map, ignoring slopes, and the trails between them as
+/// `(from, to, length)` edges on junction indices. The start is junction 0 and the end junction 1.
+fn compress(grid: &[Vec<char>]) -> (Vec<Pos>, Vec<(usize, usize, usize)>) {
+    let open = |(x, y): Pos| x < grid.len() && y < grid[0].len() && grid[x][y] != '#';
+    let neighbors = |(x, y): Pos| {
+        [(x, y + 1), (x, y.wrapping_sub(1)), (x + 1, y), (x.wrapping_sub(1), y)]
+            .into_iter()
+            .filter(move |p| open(*p))
+    };
+
+    let starting_position = (0usize, grid[0].iter().position(|c| c == &'.').unwrap());
+    let ending_position = (grid.len() - 1, grid.last().unwrap().iter().position(|c| c == &'.').unwrap());
+
+    let mut junctions = vec![starting_position, ending_position];
+    for (x, row) in grid.iter().enumerate() {
+        for y in 0..row.len() {
+            if open((x, y)) && neighbors((x, y)).count() >= 3 {
+                junctions.push((x, y));
+            }
+        }
+    }
+
+    let mut edges = vec![];
+    for (i, &junction) in junctions.iter().enumerate() {
+        for first in neighbors(junction) {
+            let (mut prev, mut curr, mut length) = (junction, first, 1);
+            loop {
+                if let Some(j) = junctions.iter().position(|p| *p == curr) {
+                    // Every trail is walked from both ends, keep one of them.
+                    if i < j {
+                        edges.push((i, j, length));
+                    }
+                    break;
+                }
+                let Some(next) = neighbors(curr).find(|p| *p != prev) else {
+                    break;
+                };
+                (prev, curr) = (curr, next);
+                length += 1;
+            }
+        }
+    }
+
+    (junctions, edges)
+}
+
+/// Longest simple path from `node` to `target`, as its length and the nodes on it in reverse.
+fn longest_path(adjacent: &[Vec<(usize, usize)>], node: usize, target: usize, visited: &mut [bool]) -> Option<(usize, Vec<usize>)> {
+    if node == target {
+        return Some((0, vec![target]));
+    }
+
+    visited[node] = true;
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    for &(next, length) in &adjacent[node] {
+        if visited[next] {
+            continue;
+        }
+        if let Some((dist, path)) = longest_path(adjacent, next, target, visited) {
+            let improves = match &best {
+                Some((best_dist, _)) => dist + length > *best_dist,
+                None => true,
+            };
+            if improves {
+                best = Some((dist + length, path));
+            }
+        }
+    }
+    visited[node] = false;
+
+    best.map(|(dist, mut path)| {
+        path.push(node);
+        (dist, path)
+    })
+}
+
+fn dfs(grid: &[Vec<char>], consider_slopes: bool, cancel: &Cancel) -> Result<usize, Cancelled<usize>> {
+    let mut search = HikeSearch::new(grid, consider_slopes);
+    while search.step() {
+        if cancel.is_cancelled() {
+            let partial = (search.max_dist > 0).then_some(search.max_dist);
+            return Err(Cancelled { partial });
+        }
+    }
+    Ok(search.max_dist)
+}
+
+/// Exhaustive longest-path search over the trail map, one stack entry at a time.
+struct HikeSearch<'a> {
+    grid: &'a [Vec<char>],
+    consider_slopes: bool,
+    ending_position: Pos,
+    current_nodes: VecDeque<(Pos, HashSet<Pos>)>,
+    max_dist: usize,
+    max_path: HashSet<Pos>,
+}
+
+impl<'a> HikeSearch<'a> {
+    fn new(grid: &'a [Vec<char>], consider_slopes: bool) -> Self {
+        let max_x = grid.len();
+
+        let starting_position = (0usize, grid[0].iter().position(|c| c == &'.').unwrap());
+        let ending_position = (max_x - 1, grid.last().unwrap().iter().position(|c| c == &'.').unwrap());
+
+        let mut current_nodes = VecDeque::new();
+        current_nodes.push_front((starting_position, HashSet::new()));
+
+        HikeSearch {
+            grid,
+            consider_slopes,
+            ending_position,
+            current_nodes,
+            max_dist: 0,
+            max_path: HashSet::new(),
+        }
+    }
+
+    /// Expands the node on top of the stack. Returns `false` once the search is exhausted.
+    fn step(&mut self) -> bool {
+        let grid = self.grid;
+        let max_x = grid.len();
+        let max_y = grid[0].len();
+
+        let Some((node, path)) = self.current_nodes.pop_front() else {
+            return false;
+        };
+
+        let (x, y)= node;
+        for (neighbor, dir) in [((x, y + 1), Direction::Right), ((x, y.wrapping_sub(1)), Direction::Left), ((x + 1, y), Direction::Down), ((x.wrapping_sub(1), y), Direction::Up)] {
+            if neighbor.0 >= max_x || neighbor.1 >= max_y {
+                continue;
+            }
+            let next_char = grid[neighbor.0][neighbor.1];
+            if next_char == '#' {
+                continue;
+            }
+            if self.consider_slopes {
+                if next_char == '>' && dir != Direction::Right {
+                    continue;
+                }
+                if next_char == '<' && dir != Direction::Left {
+                    continue;
+                }
+                if next_char == '^' && dir != Direction::Up {
+                    continue;
+                }
+                if next_char == 'v' && dir != Direction::Down {
+                    continue;
+                }
+            }
+
+            if neighbor == self.ending_position && path.len() >= self.max_dist {
+                self.max_dist = self.max_dist.max(path.len() + 1);
+                self.max_path = path.clone();
+                self.max_path.insert(neighbor);
+            }
+
+            if path.contains(&neighbor) {
+                continue;
+            }
+            let mut new_path: HashSet<(usize, usize)> = path.clone();
+            new_path.insert(neighbor);
+            self.current_nodes.push_front((neighbor, new_path));
+        }
+
+        true
+    }
+}
\ No newline at end of file
@@ -0,0 +1,262 @@
+use std::ops::{Add, Mul};
+
+use itertools::Itertools;
+use crate::utils::parse::tuple;
+use crate::utils::svg::Svg;
+use z3::ast::{Ast, Int};
+use z3::{Config, Context, SatResult, Solver};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Hailstone {
+    x: i128,
+    y: i128,
+    z: i128,
+    vx: i128,
+    vy: i128,
+    vz: i128,
+}
+
+impl From<&str> for Hailstone {
+    fn from(value: &str) -> Self {
+        let (position, velocity) = value.split_once('@').unwrap();
+        let [x, y, z] = tuple(position, ',').unwrap();
+        let [vx, vy, vz] = tuple(velocity, ',').unwrap();
+        Hailstone {
+            x,
+            y,
+            z,
+            vx,
+            vy,
+            vz,
+        }
+    }
+}
+
+impl Hailstone {
+    fn get_2d_line(&self) -> (i128, i128, i128, i128) {
+        let x1 = self.x;
+        let y1 = self.y;
+        let x2 = self.x + self.vx;
+        let y2 = self.y + self.vy;
+        (x1, y1, x2, y2)
+    }
+
+    fn point_of_2d_intersection(&self, other: &Hailstone) -> Option<(i128, i128)> {
+        let (x1, y1, x2, y2) = self.get_2d_line();
+        let (x3, y3, x4, y4) = other.get_2d_line();
+
+        let denominator = (x1 - x2) * (y3 - y4) - (y1 - y2) * (x3 - x4);
+
+        if denominator == 0 {
+            return None;
+        }
+
+        let px_n = (x1 * y2 - y1 * x2) * (x3 - x4) - (x1 - x2) * (x3 * y4 - y3 * x4);
+        let py_n = (x1 * y2 - y1 * x2) * (y3 - y4) - (y1 - y2) * (x3 * y4 - y3 * x4);
+        Some((px_n / denominator, py_n / denominator))
+    }
+
+    fn is_point_in_the_future_2d(&self, p: (i128, i128)) -> bool {
+        let (px, py) = p;
+        if px < self.x && self.vx > 0 {
+            return false;
+        }
+        if px > self.x && self.vx < 0 {
+            return false;
+        }
+        if py < self.y && self.vy > 0 {
+            return false;
+        }
+        if py > self.y && self.vy < 0 {
+            return false;
+        }
+        true
+    }
+}
+
+/// Draws the 2D paths of all hailstones inside the test area, marking the counted intersections.
+pub fn draw_trajectories(input: &str) -> Svg {
+    let hailstones = parse(input);
+    let intersections = intersections_2d(&hailstones);
+    let mut svg = Svg::new();
+    let (min, max) = (BOUND_MIN as f64, BOUND_MAX as f64);
+
+    svg.polygon(&[(min, min), (max, min), (max, max), (min, max)], "none", "gray");
+    for h in &hailstones {
+        svg.ray((h.x as f64, h.y as f64), (h.vx as f64, h.vy as f64), min, max, "steelblue");
+    }
+    for (x, y) in &intersections {
+        svg.marker((*x as f64, *y as f64), "red");
+    }
+
+    svg
+}
+
+// const BOUND_MIN: i128 = 7;
+// const BOUND_MAX: i128 = 27;
+
+const BOUND_MIN: i128 = 200000000000000;
+const BOUND_MAX: i128 = 400000000000000;
+
+fn parse(input: &str) -> Vec<Hailstone> {
+    input.lines().map(Into::into).collect_vec()
+}
+
+/// The future 2D crossings of every pair of hailstones inside the test area.
+fn intersections_2d(hailstones: &[Hailstone]) -> Vec<(i128, i128)> {
+    let mut intersections = vec![];
+    for i in 0..hailstones.len() - 1 {
+        for j in (i + 1)..hailstones.len() {
+            let (h1, h2) = (hailstones[i], hailstones[j]);
+            if let Some(intersection) = h1.point_of_2d_intersection(&h2) {
+                if intersection.0 >= BOUND_MIN
+                    && intersection.0 <= BOUND_MAX
+                    && intersection.1 >= BOUND_MIN
+                    && intersection.1 <= BOUND_MAX
+                    && h1.is_point_in_the_future_2d(intersection)
+                    && h2.is_point_in_the_future_2d(intersection)
+                {
+                    intersections.push(intersection);
+                }
+            }
+        }
+    }
+    intersections
+}
+
+pub fn part1(input: &str) -> usize {
+    intersections_2d(&parse(input)).len()
+}
+
+pub fn part2(input: &str) -> i64 {
+    let hailstones = parse(input);
+    let context = Context::new(&Config::new());
+    let solver = Solver::new(&context);
+
+    let (x, y, z) = (
+        Int::new_const(&context, "X"),
+        Int::new_const(&context, "Y"),
+        Int::new_const(&context, "Z"),
+    );
+    let (vx, vy, vz) = (
+        Int::new_const(&context, "VX"),
+        Int::new_const(&context, "VY"),
+        Int::new_const(&context, "VZ"),
+    );
+
+    let zero = Int::from_i64(&context, 0);
+
+    for (i, h) in hailstones.iter().enumerate() {
+        let ti = Int::new_const(&context, format!("t_{}", i));
+        let hx = Int::from_i64(&context, h.x as i64);
+        let hy = Int::from_i64(&context, h.y as i64);
+        let hz = Int::from_i64(&context, h.z as i64);
+        let hvx = Int::from_i64(&context, h.vx as i64);
+        let hvy = Int::from_i64(&context, h.vy as i64);
+        let hvz = Int::from_i64(&context, h.vz as i64);
+        solver.assert(&ti.gt(&zero));
+        solver.assert(&(&x).add((&vx).mul(&ti))._eq(&hx.add(hvx.mul(&ti))));
+        solver.assert(&(&y).add((&vy).mul(&ti))._eq(&hy.add(hvy.mul(&ti))));
+        solver.assert(&(&z).add((&vz).mul(&ti))._eq(&hz.add(hvz.mul(&ti))));
+    }
+
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let (x, y, z) = (
+        model.eval(&x, false).unwrap(),
+        model.eval(&y, false).unwrap(),
+        model.eval(&z, false).unwrap(),
+    );
+
+    x.as_i64().unwrap() + y.as_i64().unwrap() + z.as_i64().unwrap()
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+/// Reference for [`part1`], solving each pair of paths with exact fractions.
+pub fn reference_part1(input: &str) -> usize {
+    let hailstones = parse(input);
+
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| {
+            let (va, vb) = ((a.vx, a.vy), (b.vx, b.vy));
+            let gap = (b.x - a.x, b.y - a.y);
+            let mut den = cross(va, vb);
+            if den == 0 {
+                return false;
+            }
+            // `a` reaches the crossing at time `t / den` and `b` at time `s / den`.
+            let (mut t, mut s) = (cross(gap, vb), cross(gap, va));
+            if den < 0 {
+                (den, t, s) = (-den, -t, -s);
+            }
+            let inside = |p: i128, v: i128| (BOUND_MIN * den..=BOUND_MAX * den).contains(&(p * den + v * t));
+            t >= 0 && s >= 0 && inside(a.x, a.vx) && inside(a.y, a.vy)
+        })
+        .count()
+}
+
+/// The time at which a hailstone at `p` moving at `v` reaches `target`, if it ever does.
+fn reference_meeting(p: [i128; 3], v: [i128; 3], target: [i128; 3]) -> Option<i128> {
+    let mut time = None;
+    for axis in 0..3 {
+        let gap = target[axis] - p[axis];
+        if v[axis] == 0 {
+            if gap != 0 {
+                return None;
+            }
+        } else if gap % v[axis] != 0 || time.is_some_and(|t| t != gap / v[axis]) {
+            return None;
+        } else {
+            time = Some(gap / v[axis]);
+        }
+    }
+    time
+}
+
+/// Reference for [`part2`]. Tries every rock velocity with components within 500 in x and y.
+/// Seen from the rock, every hailstone passes through the throwing position, which the first
+/// two hailstones pin down.
+pub fn reference_part2(input: &str) -> i64 {
+    let hailstones = parse(input);
+    let position = |h: &Hailstone| [h.x, h.y, h.z];
+    let (a, b) = (hailstones[0], hailstones[1]);
+
+    for vx in -500..=500 {
+        for vy in -500..=500 {
+            let (ua, ub) = ((a.vx - vx, a.vy - vy), (b.vx - vx, b.vy - vy));
+            let den = cross(ua, ub);
+            let ta = cross((b.x - a.x, b.y - a.y), ub);
+            if den == 0 || ta % den != 0 {
+                continue;
+            }
+            let ta = ta / den;
+            let (x, y) = (a.x + ua.0 * ta, a.y + ua.1 * ta);
+
+            // Both hailstones are hit, so `b`'s time follows from x or y and fixes `vz`.
+            let Some(tb) = reference_meeting([b.x, b.y, 0], [ub.0, ub.1, 0], [x, y, 0]) else {
+                continue;
+            };
+            if ta == tb || (b.z - a.z + b.vz * tb - a.vz * ta) % (tb - ta) != 0 {
+                continue;
+            }
+            let vz = (b.z - a.z + b.vz * tb - a.vz * ta) / (tb - ta);
+            let rock = [x, y, a.z + (a.vz - vz) * ta];
+
+            let hits_all = hailstones.iter().all(|h| {
+                let relative = [h.vx - vx, h.vy - vy, h.vz - vz];
+                reference_meeting(position(h), relative, rock).is_some_and(|t| t >= 0)
+            });
+            if hits_all {
+                return rock.iter().sum::<i128>() as i64;
+            }
+        }
+    }
+    0
+}
+
@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use crate::utils::dot::Dot;
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+use rustworkx_core::petgraph::{Graph, Undirected};
+use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+
+type Components<'a> = (Graph<&'a str, (), Undirected>, Vec<(NodeIndex, NodeIndex)>, Vec<NodeIndex>);
+
+/// The component graph, its wires and one side of the minimum cut.
+fn min_cut(input: &str) -> Components<'_> {
+    let lines = input.lines().collect_vec();
+
+    let mut nodes: HashMap<&str, NodeIndex> = HashMap::new();
+
+    let mut edges: Vec<(NodeIndex, NodeIndex)> = vec![];
+
+    let mut graph: Graph<&str, (), Undirected> = UnGraph::new_undirected();
+
+    for line in &lines {
+        let (source, dests) = line.split(':').collect_tuple().unwrap();
+        let destinations = dests.split_ascii_whitespace().map(str::trim).collect_vec();
+        let source_node = get_or_insert_node(&mut nodes, &mut graph, source);
+        for dest_node in destinations {
+            let destination = get_or_insert_node(&mut nodes, &mut graph, dest_node);
+            edges.push((source_node, destination));
+        }
+    }
+
+    graph.extend_with_edges(&edges);
+
+    let result = stoer_wagner_min_cut(&graph, |_| Ok::<i32, i32>(1));
+
+
+    let (_, partition) = result.unwrap().unwrap();
+
+    (graph, edges, partition)
+}
+
+pub fn part1(input: &str) -> usize {
+    let (graph, _, partition) = min_cut(input);
+    (graph.node_count() - partition.len()) * partition.len()
+}
+
+/// Reference for [`part1`], cutting every combination of three wires and checking whether the
+/// rest still holds together.
+pub fn reference_part1(input: &str) -> usize {
+    let wires = input
+        .lines()
+        .flat_map(|line| {
+            let (source, dests) = line.split_once(':').unwrap();
+            dests.split_whitespace().map(move |d| (source, d))
+        })
+        .collect_vec();
+    let names = wires.iter().flat_map(|&(a, b)| [a, b]).unique().collect_vec();
+
+    for cut in (0..wires.len()).combinations(3) {
+        let mut reached = HashSet::from([names[0]]);
+        let mut stack = vec![names[0]];
+        while let Some(name) = stack.pop() {
+            for (i, &(a, b)) in wires.iter().enumerate() {
+                let next = if a == name { b } else if b == name { a } else { continue };
+                if !cut.contains(&i) && reached.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        if reached.len() < names.len() {
+            return reached.len() * (names.len() - reached.len());
+        }
+    }
+    0
+}
+
+/// The component graph, coloured by side of the minimum cut, with the cut edges highlighted.
+pub fn draw_components(input: &str) -> Dot {
+    let (graph, edges, partition) = min_cut(input);
+    let partition: HashSet<NodeIndex> = partition.iter().copied().collect();
+    let mut dot = Dot::undirected();
+
+    for node in graph.node_indices() {
+        let color = if partition.contains(&node) { "lightblue" } else { "lightyellow" };
+        dot.fill(graph[node], color);
+    }
+
+    for (a, b) in &edges {
+        dot.edge(graph[*a], graph[*b], None);
+    }
+
+    for (a, b) in &edges {
+        if partition.contains(a) != partition.contains(b) {
+            dot.highlight(graph[*a], graph[*b]);
+        }
+    }
+
+    dot
+}
+
+fn get_or_insert_node<'a>(nodes: &mut HashMap<&'a str, NodeIndex>, graph: &mut Graph<&'a str, (), Undirected>, source: &'a str) -> NodeIndex {
+    let source_node = if nodes.contains_key(source) {
+        *nodes.get(source).unwrap()
+    } else {
+        let node_index = graph.add_node(source);
+        nodes.insert(source, node_index);
+        node_index
+    };
+    source_node
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::utils::parse::ParseError;
+
+/// A number in the schematic, spanning columns `start..end` of its row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Anything in the schematic that is neither a digit nor a `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub char: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// An engine schematic, with which numbers touch which symbols (diagonals included) worked out
+/// once when it is parsed.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle3::Schematic;
+///
+/// let schematic: Schematic = "467..114..\n...*......\n..35..633.\n......#...".parse().unwrap();
+/// let parts = schematic.parts().map(|n| n.value).collect::<Vec<_>>();
+/// assert_eq!(parts, [467, 35, 633]);
+///
+/// let gears = schematic.symbols_with_parts(2).map(|s| s.char).collect::<Vec<_>>();
+/// assert_eq!(gears, ['*']);
+/// assert_eq!(schematic.gear_ratios('*').collect::<Vec<_>>(), [467 * 35]);
+/// assert_eq!(schematic.gear_ratios('#').count(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// For each number, the indices of the symbols it touches.
+    number_symbols: Vec<Vec<usize>>,
+    /// For each symbol, the indices of the numbers it touches.
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+
+        for (row, line) in s.lines().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            let mut col = 0;
+            while col < chars.len() {
+                let c = chars[col];
+                if c.is_ascii_digit() {
+                    let start = col;
+                    while col < chars.len() && chars[col].is_ascii_digit() {
+                        col += 1;
+                    }
+                    let digits = chars[start..col].iter().collect::<String>();
+                    let value = digits.parse().map_err(|e| {
+                        ParseError::new(line, format!("invalid number `{digits}`: {e}")).at_line(row + 1)
+                    })?;
+                    numbers.push(Number { value, row, start, end: col });
+                    continue;
+                }
+                if c != '.' && !c.is_whitespace() {
+                    symbols.push(Symbol { char: c, row, col });
+                }
+                col += 1;
+            }
+        }
+
+        let symbol_at: HashMap<(usize, usize), usize> =
+            symbols.iter().enumerate().map(|(i, s)| ((s.row, s.col), i)).collect();
+        let mut number_symbols = vec![vec![]; numbers.len()];
+        let mut symbol_numbers = vec![vec![]; symbols.len()];
+
+        for (i, n) in numbers.iter().enumerate() {
+            for row in n.row.saturating_sub(1)..=n.row + 1 {
+                for col in n.start.saturating_sub(1)..=n.end {
+                    if let Some(&j) = symbol_at.get(&(row, col)) {
+                        number_symbols[i].push(j);
+                        symbol_numbers[j].push(i);
+                    }
+                }
+            }
+        }
+
+        Ok(Schematic {
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+}
+
+impl Schematic {
+    /// The part numbers: every number touching at least one symbol, in reading order.
+    pub fn parts(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(n, _)| n)
+    }
+
+    /// The numbers touching `symbol`, in reading order.
+    pub fn adjacent_parts(&self, symbol: &Symbol) -> impl Iterator<Item = &Number> {
+        let i = self.symbols.iter().position(|s| s == symbol);
+        i.into_iter()
+            .flat_map(|i| &self.symbol_numbers[i])
+            .map(|&n| &self.numbers[n])
+    }
+
+    /// The symbols touching exactly `count` numbers, in reading order.
+    pub fn symbols_with_parts(&self, count: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbols
+            .iter()
+            .zip(&self.symbol_numbers)
+            .filter(move |(_, numbers)| numbers.len() == count)
+            .map(|(s, _)| s)
+    }
+
+    /// The gear ratio of every `symbol` touching exactly two numbers: the product of the two.
+    pub fn gear_ratios(&self, symbol: char) -> impl Iterator<Item = u32> + '_ {
+        self.symbols
+            .iter()
+            .zip(&self.symbol_numbers)
+            .filter(move |(s, numbers)| s.char == symbol && numbers.len() == 2)
+            .map(|(_, numbers)| numbers.iter().map(|&n| self.numbers[n].value).product())
+    }
+}
+
+fn schematic(input: &str) -> Schematic {
+    input.parse().unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn part1(input: &str) -> u32 {
+    schematic(input).parts().map(|n| n.value).sum()
+}
+
+pub fn part2(input: &str) -> u32 {
+    schematic(input).gear_ratios('*').sum()
+}
+
+/// Every number in the schematic as `(row, start, end, value)`.
+fn numbers(schematic: &[Vec<char>]) -> Vec<(usize, usize, usize, u32)> {
+    let mut found = vec![];
+    for (i, row) in schematic.iter().enumerate() {
+        let mut j = 0;
+        while j < row.len() {
+            if row[j].is_ascii_digit() {
+                let start = j;
+                while j < row.len() && row[j].is_ascii_digit() {
+                    j += 1;
+                }
+                let value = row[start..j].iter().collect::<String>().parse().unwrap();
+                found.push((i, start, j, value));
+            } else {
+                j += 1;
+            }
+        }
+    }
+    found
+}
+
+/// Whether cell `(x, y)` touches the number spanning `start..end` of `row`, diagonals included.
+fn touches(x: usize, y: usize, (row, start, end, _): (usize, usize, usize, u32)) -> bool {
+    x + 1 >= row && x <= row + 1 && y + 1 >= start && y <= end
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// Reference for [`part1`], checking the cells around each number for a symbol.
+pub fn reference_part1(input: &str) -> u32 {
+    let schematic: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+
+    numbers(&schematic)
+        .into_iter()
+        .filter(|&n| {
+            schematic.iter().enumerate().any(|(x, row)| {
+                row.iter().enumerate().any(|(y, &c)| is_symbol(c) && touches(x, y, n))
+            })
+        })
+        .map(|n| n.3)
+        .sum()
+}
+
+/// Reference for [`part2`], collecting the numbers touching each `*`.
+pub fn reference_part2(input: &str) -> u32 {
+    let schematic: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let numbers = numbers(&schematic);
+
+    let mut sum = 0;
+    for (x, row) in schematic.iter().enumerate() {
+        for (y, &c) in row.iter().enumerate() {
+            if c == '*' {
+                let adjacent: Vec<u32> = numbers.iter().filter(|&&n| touches(x, y, n)).map(|n| n.3).collect();
+                if adjacent.len() == 2 {
+                    sum += adjacent[0] * adjacent[1];
+                }
+            }
+        }
+    }
+    sum
+}
@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::utils::parse::{ints, split_label, ParseError};
+
+/// A scratchcard with its ID as written in the input.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle4::Scratchcard;
+///
+/// let card: Scratchcard = "Card 3: 5 5 7 | 5 5 8".parse().unwrap();
+/// assert_eq!((card.id, card.matches), (3, 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub yours: Vec<u32>,
+    /// How many different numbers are both winning numbers and yours. A number written twice
+    /// only matches once.
+    pub matches: usize,
+}
+
+impl FromStr for Scratchcard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, numbers) = split_label(s)?;
+        let id = label
+            .strip_prefix("Card")
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| ParseError::new(s, "expected `Card <id>:`"))?;
+        let (winning, yours) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(s, "expected `<winning numbers> | <your numbers>`"))?;
+        let (winning, yours): (Vec<u32>, Vec<u32>) = (ints(winning)?, ints(yours)?);
+
+        let winning_set: HashSet<u32> = winning.iter().copied().collect();
+        let yours_set: HashSet<u32> = yours.iter().copied().collect();
+        let matches = winning_set.intersection(&yours_set).count();
+
+        Ok(Scratchcard {
+            id,
+            winning,
+            yours,
+            matches,
+        })
+    }
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Scratchcard>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.at_line(i + 1)))
+        .collect()
+}
+
+/// How cards are scored and how many copies they win.
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    /// The first match scores 1 point and every further one multiplies the score by this.
+    pub base: u32,
+    /// How many of the cards below a card with this many matches get a copy. Copies of cards
+    /// past the end of the table are lost.
+    pub window: fn(usize) -> usize,
+}
+
+impl Rules {
+    /// The rules of the puzzle: points double, and `n` matches win a copy of the next `n` cards.
+    pub const PUZZLE: Rules = Rules {
+        base: 2,
+        window: |matches| matches,
+    };
+
+    /// The points `card` is worth, or an error naming the card if that doesn't fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle4::{Rules, Scratchcard};
+    ///
+    /// let card: Scratchcard = "Card 1: 1 2 3 | 1 2 3".parse().unwrap();
+    /// assert_eq!(Rules::PUZZLE.score(&card), Ok(4));
+    /// assert_eq!(Rules { base: 3, ..Rules::PUZZLE }.score(&card), Ok(9));
+    ///
+    /// let numbers = (1..=40).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+    /// let lucky: Scratchcard = format!("Card 2: {numbers} | {numbers}").parse().unwrap();
+    /// assert!(Rules::PUZZLE.score(&lucky).is_err());
+    /// ```
+    pub fn score(&self, card: &Scratchcard) -> Result<u32, String> {
+        match card.matches {
+            0 => Ok(0),
+            m => u32::try_from(m - 1)
+                .ok()
+                .and_then(|exponent| self.base.checked_pow(exponent))
+                .ok_or_else(|| format!("card {} with {m} matches scores more than {} points", card.id, u32::MAX)),
+        }
+    }
+
+    /// How many copies of every card end up being scratched, and where they came from, or an
+    /// error naming the first card with more copies than fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle4::{parse_cards, Rules};
+    ///
+    /// let cards = parse_cards("Card 7: 1 2 | 1 2\nCard 8: 3 | 3\nCard 9: 4 | 5\nCard 10: 6 | 7").unwrap();
+    /// let breakdown = Rules::PUZZLE.copies(&cards).unwrap();
+    /// assert_eq!(breakdown.iter().map(|b| b.copies).collect::<Vec<_>>(), [1, 2, 4, 1]);
+    /// assert_eq!(breakdown[2].won_from, [(7, 1), (8, 2)]);
+    ///
+    /// // Twice as many cards below win a copy, so card 10 gets some too.
+    /// let wider = Rules { window: |matches| matches * 2, ..Rules::PUZZLE };
+    /// let breakdown = wider.copies(&cards).unwrap();
+    /// assert_eq!(breakdown.iter().map(|b| b.copies).collect::<Vec<_>>(), [1, 2, 4, 4]);
+    /// assert_eq!(breakdown[3].won_from, [(7, 1), (8, 2)]);
+    ///
+    /// // With a wider window the copies grow exponentially down the table.
+    /// let many = (1..=60).map(|id| format!("Card {id}: 1 | 1")).collect::<Vec<_>>().join("\n");
+    /// let many = parse_cards(&many).unwrap();
+    /// assert_eq!(Rules::PUZZLE.copies(&many).unwrap()[59].copies, 60);
+    /// assert!(wider.copies(&many).is_err());
+    /// ```
+    pub fn copies(&self, cards: &[Scratchcard]) -> Result<Vec<Breakdown>, String> {
+        let mut breakdown: Vec<Breakdown> = cards
+            .iter()
+            .map(|card| Breakdown {
+                id: card.id,
+                copies: 1,
+                won_from: vec![],
+            })
+            .collect();
+
+        for (i, card) in cards.iter().enumerate() {
+            let copies = breakdown[i].copies;
+            let end = (i + 1 + (self.window)(card.matches)).min(cards.len());
+            for won in &mut breakdown[i + 1..end] {
+                won.copies = won
+                    .copies
+                    .checked_add(copies)
+                    .ok_or_else(|| format!("card {} ends up with more than {} copies", won.id, u32::MAX))?;
+                won.won_from.push((card.id, copies));
+            }
+        }
+
+        Ok(breakdown)
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+/// How many copies of a card were scratched, counting the original.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakdown {
+    pub id: u32,
+    pub copies: u32,
+    /// The cards above that won copies of this one, with how many copies each won.
+    pub won_from: Vec<(u32, u32)>,
+}
+
+fn cards(input: &str) -> Vec<Scratchcard> {
+    parse_cards(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn part1(input: &str) -> u32 {
+    cards(input)
+        .iter()
+        .map(|card| Rules::PUZZLE.score(card).unwrap_or_else(|e| panic!("{e}")))
+        .sum()
+}
+
+pub fn part2(input: &str) -> u32 {
+    let breakdown = Rules::PUZZLE.copies(&cards(input)).unwrap_or_else(|e| panic!("{e}"));
+    breakdown.iter().map(|b| b.copies).sum()
+}
+
+/// The number of different winning numbers you have on each card, compared pairwise.
+fn matches(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, yours) = numbers.split_once('|').unwrap();
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+            let mut yours: Vec<&str> = yours.split_whitespace().collect();
+            yours.sort();
+            yours.dedup();
+            yours.into_iter().filter(|n| winning.contains(n)).count()
+        })
+        .collect()
+}
+
+/// Reference for [`part1`], doubling the score once per extra match.
+pub fn reference_part1(input: &str) -> u32 {
+    matches(input)
+        .into_iter()
+        .map(|m| (0..m).fold(0, |score, _| if score == 0 { 1 } else { score * 2 }))
+        .sum()
+}
+
+/// Reference for [`part2`], processing every copy of every card through a queue.
+pub fn reference_part2(input: &str) -> u32 {
+    let matches = matches(input);
+    let mut queue: Vec<usize> = (0..matches.len()).collect();
+    let mut processed = 0;
+
+    while let Some(card) = queue.pop() {
+        processed += 1;
+        queue.extend((card + 1..=card + matches[card]).filter(|&c| c < matches.len()));
+    }
+    processed
+}
+
@@ -0,0 +1,419 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use crate::utils::interval::Interval;
+use crate::utils::parse::{blocks, ints, split_label, ParseError};
+
+/// One `<from>-to-<to> map:` block of the almanac.
+#[derive(Debug)]
+pub struct Stage {
+    pub from: String,
+    pub to: String,
+    pub map: RangedMapList,
+}
+
+/// The seeds and the maps between categories, looked up by category name rather than by where
+/// the maps appear in the input.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle5::Almanac;
+///
+/// let almanac: Almanac = "seeds: 79 14
+///
+/// seed-to-soil map:
+/// 50 98 2
+/// 52 50 48
+///
+/// soil-to-water map:
+/// 0 15 37
+/// 37 52 2
+/// 39 0 15"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(almanac.lookup("seed", "soil", 79), Some(81));
+/// assert_eq!(almanac.lookup("seed", "water", 79), Some(81));
+/// assert_eq!(almanac.lookup("water", "seed", 81), Some(79));
+/// assert_eq!(almanac.lookup("seed", "light", 79), None);
+/// ```
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub stages: Vec<Stage>,
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blocks = blocks(s);
+        let (_, seeds) = split_label(blocks.first().copied().unwrap_or_default())?;
+        let seeds = ints(seeds)?;
+
+        let stages = blocks[1..]
+            .iter()
+            .map(|block| {
+                let header = block.lines().next().unwrap_or_default();
+                let (from, to) = header
+                    .trim()
+                    .strip_suffix("map:")
+                    .and_then(|name| name.trim().split_once("-to-"))
+                    .ok_or_else(|| ParseError::new(header, "expected `<from>-to-<to> map:`"))?;
+                let entries = block
+                    .lines()
+                    .skip(1)
+                    .map(|line| match ints(line)?[..] {
+                        [destination_start, source_start, range] => Ok(RangedMap {
+                            source_start,
+                            destination_start,
+                            range,
+                        }),
+                        _ => Err(ParseError::new(line, "expected `<destination> <source> <length>`")),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Stage {
+                    from: from.to_owned(),
+                    to: to.to_owned(),
+                    map: RangedMapList::new_sorted(entries),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac { seeds, stages })
+    }
+}
+
+impl Almanac {
+    /// The stages leading from `from` to `to`, and whether they lead there backwards (from `to`
+    /// to `from`). The shortest chain is used, forwards if there is one.
+    fn path(&self, from: &str, to: &str) -> Option<(Vec<&Stage>, bool)> {
+        let forwards = |from: &str, to: &str| {
+            let mut previous: HashMap<&str, &Stage> = HashMap::new();
+            let mut queue = VecDeque::from([from]);
+            while let Some(category) = queue.pop_front() {
+                if category == to {
+                    let mut path = vec![];
+                    let mut current = to;
+                    while current != from {
+                        let stage = previous[current];
+                        path.push(stage);
+                        current = &stage.from;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                for stage in self.stages.iter().filter(|s| s.from == category) {
+                    if stage.to != from && !previous.contains_key(stage.to.as_str()) {
+                        previous.insert(&stage.to, stage);
+                        queue.push_back(&stage.to);
+                    }
+                }
+            }
+            None
+        };
+
+        forwards(from, to)
+            .map(|path| (path, false))
+            .or_else(|| forwards(to, from).map(|path| (path, true)))
+    }
+
+    /// The map from category `from` to category `to`, composed from every stage in between.
+    /// Going backwards, e.g. from location to seed, gives the [`inverse`](PiecewiseMap::inverse)
+    /// of the map going forwards.
+    pub fn map(&self, from: &str, to: &str) -> Option<PiecewiseMap> {
+        let (path, backwards) = self.path(from, to)?;
+        let map = path.iter().fold(PiecewiseMap::from(&RangedMapList::new_sorted(vec![])), |map, stage| {
+            map.compose(&PiecewiseMap::from(&stage.map))
+        });
+
+        Some(if backwards { map.inverse() } else { map })
+    }
+
+    /// Where `value` of category `from` ends up in category `to`, or `None` if the almanac has no
+    /// way between them or nothing maps to `value` going backwards.
+    pub fn lookup(&self, from: &str, to: &str, value: i64) -> Option<i64> {
+        self.map(from, to)?.get(value)
+    }
+}
+
+/// A map made of pieces that each shift an [`Interval`] of numbers by an offset. Numbers not
+/// covered by any piece aren't mapped at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    /// Sorted pieces that don't overlap, each with its offset.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl From<&RangedMapList> for PiecewiseMap {
+    /// The map a list of ranges describes, which leaves numbers outside all of them as they are.
+    fn from(list: &RangedMapList) -> Self {
+        let mut pieces = vec![];
+        let mut cursor = i64::MIN;
+        for m in &list.maps {
+            // Where lines overlap, the one starting first wins, as in `RangedMapList::get`.
+            let source = Interval::with_len(m.source_start, m.range).intersect(Interval::new(cursor, i64::MAX));
+            if source.is_empty() {
+                continue;
+            }
+            pieces.push((Interval::new(cursor, source.start), 0));
+            pieces.push((source, m.destination_start - m.source_start));
+            cursor = source.end;
+        }
+        pieces.push((Interval::new(cursor, i64::MAX), 0));
+
+        PiecewiseMap::new(pieces)
+    }
+}
+
+impl PiecewiseMap {
+    /// Sorts the pieces, drops empty ones and merges neighbours with the same offset.
+    fn new(mut pieces: Vec<(Interval, i64)>) -> Self {
+        pieces.retain(|(piece, _)| !piece.is_empty());
+        pieces.sort_unstable();
+        let mut merged: Vec<(Interval, i64)> = vec![];
+        for (piece, offset) in pieces {
+            match merged.last_mut() {
+                Some((last, last_offset)) if last.end == piece.start && *last_offset == offset => last.end = piece.end,
+                _ => merged.push((piece, offset)),
+            }
+        }
+        PiecewiseMap { pieces: merged }
+    }
+
+    /// The pieces overlapping `range`.
+    fn overlapping(&self, range: Interval) -> impl Iterator<Item = &(Interval, i64)> {
+        let first = self.pieces.partition_point(|(piece, _)| piece.end <= range.start);
+        self.pieces[first..].iter().take_while(move |(piece, _)| piece.start < range.end)
+    }
+
+    pub fn get(&self, value: i64) -> Option<i64> {
+        self.overlapping(Interval::with_len(value, 1))
+            .next()
+            .map(|(_, offset)| value + offset)
+    }
+
+    /// Maps every number in `range` at once. The result is the image of each piece of `range`
+    /// that one piece of the map covers, in order. Those pieces partition the mapped part of
+    /// `range`, so every mapped number in it is mapped exactly once and no empty intervals are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::interval::Interval;
+    /// use rust_aoc_2023::y2023::days::puzzle5::{PiecewiseMap, RangedMap, RangedMapList};
+    ///
+    /// let map = PiecewiseMap::from(&RangedMapList::new_sorted(vec![
+    ///     RangedMap { source_start: 98, destination_start: 50, range: 2 },
+    ///     RangedMap { source_start: 50, destination_start: 52, range: 48 },
+    /// ]));
+    /// assert_eq!(
+    ///     map.image(Interval::new(40, 100)),
+    ///     [Interval::new(40, 50), Interval::new(52, 100), Interval::new(50, 52)],
+    /// );
+    /// assert_eq!(map.image(Interval::new(99, 101)), [Interval::new(51, 52), Interval::new(100, 101)]);
+    /// ```
+    pub fn image(&self, range: Interval) -> Vec<Interval> {
+        self.overlapping(range)
+            .map(|&(piece, offset)| piece.intersect(range).shift(offset))
+            .filter(|image| !image.is_empty())
+            .collect()
+    }
+
+    /// The smallest number that something in `range` maps to.
+    pub fn min_image(&self, range: Interval) -> Option<i64> {
+        self.image(range).iter().map(|image| image.start).min()
+    }
+
+    /// This map followed by `then`.
+    pub fn compose(&self, then: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = vec![];
+        for &(piece, offset) in &self.pieces {
+            for &(then_piece, then_offset) in then.overlapping(piece.shift(offset)) {
+                let through = piece.shift(offset).intersect(then_piece).shift(-offset);
+                pieces.push((through, offset + then_offset));
+            }
+        }
+        PiecewiseMap::new(pieces)
+    }
+
+    /// The map back from images to what maps to them. A number with several preimages maps back
+    /// to the smallest of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle5::{Almanac, PiecewiseMap};
+    ///
+    /// let almanac: Almanac = "seeds: 1\n\nseed-to-soil map:\n20 10 5\n12 0 10".parse().unwrap();
+    /// let map = almanac.map("seed", "soil").unwrap();
+    /// let inverse = map.inverse();
+    ///
+    /// assert_eq!(inverse.get(22), Some(12));
+    /// assert_eq!(inverse.get(12), Some(0));
+    /// assert_eq!(inverse.get(5), None);
+    /// assert_eq!(inverse.compose(&map).get(24), Some(24));
+    /// ```
+    pub fn inverse(&self) -> PiecewiseMap {
+        // Only the pieces reaching out to i64::MIN or i64::MAX are unbounded, and those always
+        // have offset 0, so shifting cannot overflow.
+        let images: Vec<(Interval, i64)> = self
+            .pieces
+            .iter()
+            .map(|&(piece, offset)| (piece.shift(offset), offset))
+            .collect();
+        let mut cuts: Vec<i64> = images.iter().flat_map(|(image, _)| [image.start, image.end]).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let pieces = cuts
+            .windows(2)
+            .filter_map(|cut| {
+                let segment = Interval::new(cut[0], cut[1]);
+                images
+                    .iter()
+                    .filter(|(image, _)| image.intersect(segment) == segment)
+                    .map(|&(_, offset)| offset)
+                    .max()
+                    .map(|offset| (segment, -offset))
+            })
+            .collect();
+
+        PiecewiseMap::new(pieces)
+    }
+}
+
+fn almanac(input: &str) -> Almanac {
+    input.parse().unwrap_or_else(|e| panic!("{e}"))
+}
+
+fn seed_to_location(almanac: &Almanac) -> PiecewiseMap {
+    almanac
+        .map("seed", "location")
+        .expect("the almanac should map seeds to locations")
+}
+
+pub fn part1(input: &str) -> i64 {
+    let almanac = almanac(input);
+    let map = seed_to_location(&almanac);
+
+    almanac.seeds.iter().filter_map(|&seed| map.get(seed)).min().unwrap()
+}
+
+pub fn part2(input: &str) -> i64 {
+    let almanac = almanac(input);
+    let map = seed_to_location(&almanac);
+
+    almanac
+        .seeds
+        .chunks(2)
+        .filter_map(|seed| map.min_image(Interval::with_len(seed[0], seed[1])))
+        .min()
+        .unwrap()
+}
+
+/// Applies the raw map lines to `val`, using the first line whose source range contains it.
+fn reference_location(blocks: &[&str], seed: i64) -> i64 {
+    blocks[1..].iter().fold(seed, |val, block| {
+        string_to_entries(block)
+            .iter()
+            .find(|e| (e[1]..e[1] + e[2]).contains(&val))
+            .map_or(val, |e| val - e[1] + e[0])
+    })
+}
+
+/// Reference for [`part1`], reading the maps straight from the text.
+pub fn reference_part1(input: &str) -> i64 {
+    let blocks = blocks(input);
+    let seeds: Vec<i64> = ints(split_label(blocks[0]).unwrap().1).unwrap();
+
+    seeds.iter().map(|&seed| reference_location(&blocks, seed)).min().unwrap()
+}
+
+/// Reference for [`part2`], mapping every seed of every range one at a time.
+pub fn reference_part2(input: &str) -> i64 {
+    let blocks = blocks(input);
+    let seeds: Vec<i64> = ints(split_label(blocks[0]).unwrap().1).unwrap();
+
+    seeds
+        .chunks(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| reference_location(&blocks, seed))
+        .min()
+        .unwrap()
+}
+
+fn string_to_entries(line: &str) -> Vec<Vec<i64>> {
+    line.lines()
+        .skip(1)
+        .map(|s| ints(s).unwrap())
+        .collect::<Vec<_>>()
+}
+
+/// One line of a map: `range` numbers from `source_start` on map to as many from
+/// `destination_start` on.
+#[derive(Debug)]
+pub struct RangedMap {
+    pub source_start: i64,
+    pub destination_start: i64,
+    pub range: i64,
+}
+
+impl RangedMap {
+    fn get(&self, val: i64) -> Option<i64> {
+        if val >= self.source_start && val < self.source_start + self.range {
+            return Some(self.destination_start + (val - self.source_start));
+        }
+        None
+    }
+}
+
+/// The lines of one map, sorted by where their source ranges start. Numbers outside all of them
+/// map to themselves.
+#[derive(Debug)]
+pub struct RangedMapList {
+    maps: Vec<RangedMap>,
+}
+
+impl RangedMapList {
+    pub fn new_sorted(mut maps: Vec<RangedMap>) -> RangedMapList {
+        maps.sort_by_key(|m| m.source_start);
+        RangedMapList { maps }
+    }
+
+    pub fn get(&self, val: i64) -> i64 {
+        self.maps
+            .iter()
+            .map(|m| m.get(val))
+            .filter(Option::is_some)
+            .nth(0)
+            .unwrap_or(Some(val))
+            .unwrap()
+    }
+
+    /// Maps every number in `range` at once, as [`PiecewiseMap::image`] does. Numbers no line
+    /// covers keep their value, so the lengths of the result add up to the length of `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::utils::interval::Interval;
+    /// use rust_aoc_2023::y2023::days::puzzle5::{RangedMap, RangedMapList};
+    ///
+    /// let map = RangedMapList::new_sorted(vec![
+    ///     RangedMap { source_start: 98, destination_start: 50, range: 2 },
+    ///     RangedMap { source_start: 50, destination_start: 52, range: 48 },
+    /// ]);
+    /// assert_eq!(
+    ///     map.get_ranged(Interval::new(40, 100)),
+    ///     [Interval::new(40, 50), Interval::new(52, 100), Interval::new(50, 52)],
+    /// );
+    /// assert_eq!(map.get_ranged(Interval::new(99, 101)), [Interval::new(51, 52), Interval::new(100, 101)]);
+    /// ```
+    pub fn get_ranged(&self, range: Interval) -> Vec<Interval> {
+        PiecewiseMap::from(self).image(range)
+    }
+}
@@ -0,0 +1,231 @@
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::utils::parse::{ints, split_label};
+
+/// A race and the best distance anyone has gone in it, in ms and mm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub record: u128,
+}
+
+/// How fast the boat goes after the button has been held for some number of ms.
+#[derive(Debug, Clone, Copy)]
+pub enum Speed {
+    /// Gains `per_ms` mm/ms for every ms the button is held.
+    Linear { per_ms: u128 },
+    /// Like [`Linear`](Speed::Linear), but never faster than `max` mm/ms.
+    Capped { per_ms: u128, max: u128 },
+    /// Any speed for a hold time. Every hold time is tried in turn, so this takes time
+    /// proportional to the length of the race.
+    Custom(fn(u128) -> u128),
+}
+
+impl Speed {
+    /// The speed of the puzzle: 1 mm/ms more for every ms held.
+    pub const PUZZLE: Speed = Speed::Linear { per_ms: 1 };
+
+    pub fn after(&self, hold: u128) -> u128 {
+        match *self {
+            Speed::Linear { per_ms } => per_ms * hold,
+            Speed::Capped { per_ms, max } => (per_ms * hold).min(max),
+            Speed::Custom(speed) => speed(hold),
+        }
+    }
+}
+
+impl Race {
+    /// The hold times that beat the record, as ranges in increasing order. Linear and capped
+    /// speeds are solved in constant time and always give at most one range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_aoc_2023::y2023::days::puzzle6::{Race, Speed};
+    ///
+    /// let race = Race { time: 30, record: 200 };
+    /// assert_eq!(race.winning_holds(Speed::PUZZLE), [11..=19]);
+    /// assert_eq!(race.winning_holds(Speed::Linear { per_ms: 2 }), [4..=26]);
+    /// assert_eq!(race.winning_holds(Speed::Capped { per_ms: 1, max: 12 }), [11..=13]);
+    ///
+    /// // The boat crawls until the button has been held for 10 ms, so the shortest holds win too.
+    /// let late_start = Speed::Custom(|hold| if hold < 10 { 1 } else { 100 });
+    /// assert_eq!(Race { time: 20, record: 12 }.winning_holds(late_start), [0..=7, 10..=19]);
+    /// ```
+    pub fn winning_holds(&self, speed: Speed) -> Vec<RangeInclusive<u128>> {
+        let Race { time, record } = *self;
+        let ranges = match speed {
+            Speed::Linear { per_ms: 0 } | Speed::Capped { per_ms: 0, .. } => vec![],
+            Speed::Linear { per_ms } => winning_holds(time, record / per_ms)
+                .map(|(first, last)| first..=last)
+                .into_iter()
+                .collect(),
+            Speed::Capped { per_ms, max } => {
+                // Up to `full`, the boat is as fast as without the cap. After that the distance
+                // only shrinks with every ms held.
+                let full = max / per_ms;
+                let uncapped = winning_holds(time, record / per_ms)
+                    .map(|(first, last)| first..=last.min(full))
+                    .filter(|holds| !holds.is_empty());
+                let capped = (max > 0 && full < time)
+                    .then(|| full + 1..=time.saturating_sub(record / max + 1))
+                    .filter(|holds| !holds.is_empty());
+                uncapped.into_iter().chain(capped).collect()
+            }
+            Speed::Custom(_) => (0..=time)
+                .filter(|&hold| speed.after(hold) * (time - hold) > record)
+                .map(|hold| hold..=hold)
+                .collect(),
+        };
+
+        ranges
+            .into_iter()
+            .coalesce(|a, b| {
+                if *a.end() + 1 == *b.start() {
+                    Ok(*a.start()..=*b.end())
+                } else {
+                    Err((a, b))
+                }
+            })
+            .collect()
+    }
+
+    /// How many hold times beat the record.
+    pub fn ways_to_win(&self, speed: Speed) -> u128 {
+        self.winning_holds(speed)
+            .iter()
+            .map(|holds| holds.end() - holds.start() + 1)
+            .sum()
+    }
+}
+
+/// The races of the input, one per column.
+pub fn parse_races(input: &str) -> Vec<Race> {
+    let [ref times, ref records] = input
+        .lines()
+        .map(|s| ints(split_label(s).unwrap().1).unwrap())
+        .collect_vec()[..]
+    else {
+        panic!("expected a line of times and a line of records")
+    };
+
+    times.iter().zip(records).map(|(&time, &record)| Race { time, record }).collect()
+}
+
+/// The single race of part 2, reading each line as one number with bad kerning.
+pub fn parse_long_race(input: &str) -> Race {
+    let [time, record] = input
+        .lines()
+        .map(|s| split_label(s).unwrap().1.replace(' ', "").parse().unwrap())
+        .collect_vec()[..]
+    else {
+        panic!("expected a line of times and a line of records")
+    };
+
+    Race { time, record }
+}
+
+pub fn part1(input: &str) -> u128 {
+    parse_races(input).iter().map(|race| race.ways_to_win(Speed::PUZZLE)).product()
+}
+
+pub fn part2(input: &str) -> u128 {
+    parse_long_race(input).ways_to_win(Speed::PUZZLE)
+}
+
+/// Tries every hold time from zero up to the whole race.
+fn reference_ways(time: u128, record: u128) -> u128 {
+    (0..=time).filter(|hold| hold * (time - hold) > record).count() as u128
+}
+
+/// The numbers of each line, read as separate numbers or with the spaces removed.
+fn reference_races(input: &str, kerning: bool) -> Vec<(u128, u128)> {
+    let rows: Vec<Vec<String>> = input
+        .lines()
+        .map(|line| {
+            let numbers = line.split(':').nth(1).unwrap().split_whitespace().map(str::to_owned);
+            if kerning {
+                vec![numbers.collect::<String>()]
+            } else {
+                numbers.collect()
+            }
+        })
+        .collect();
+
+    rows[0].iter().zip(&rows[1]).map(|(t, d)| (t.parse().unwrap(), d.parse().unwrap())).collect()
+}
+
+/// Reference for [`part1`], trying every hold time of every race.
+pub fn reference_part1(input: &str) -> u128 {
+    reference_races(input, false).into_iter().map(|(t, d)| reference_ways(t, d)).product()
+}
+
+/// Reference for [`part2`], trying every hold time of the single long race.
+pub fn reference_part2(input: &str) -> u128 {
+    reference_races(input, true).into_iter().map(|(t, d)| reference_ways(t, d)).product()
+}
+
+/// The hold times around the middle of the race that beat `record`, as `(first, last)`.
+///
+/// Holding for `t` of `time` ms goes `t * (time - t)` mm. Writing `time = 2h + r` with `r` 0 or 1
+/// and `t = h - k`, that is `h * (h + r) - k * (k + r)`, so `t` wins when
+/// `k * (k + r) < h * (h + r) - record`. The largest such `k` follows from an integer square
+/// root, and by symmetry `time - t` wins too. A hold that only ties the record doesn't win.
+///
+/// Races of 2^65 ms or more can go further than a `u128` holds, so there is no margin to take
+/// the root of. Those are left to [`first_winning_hold`] instead.
+fn winning_holds(time: u128, record: u128) -> Option<(u128, u128)> {
+    let (h, r) = (time / 2, time % 2);
+    let Some(best) = h.checked_mul(h + r) else {
+        let first = first_winning_hold(time, record);
+        return Some((first, time - first));
+    };
+    let margin = best.checked_sub(record).filter(|&m| m > 0)?;
+
+    // The largest `k` with `k * (k + r) < margin`. `root * root <= margin`, so only `root` itself
+    // can be one too many.
+    let root = margin.isqrt();
+    let k = if root * (root + r) < margin { root } else { root - 1 };
+
+    Some((h - k, h + r + k))
+}
+
+/// The shortest hold that beats `record` in a race where holding for half of `time` goes further
+/// than any `u128`, found by bisecting the first half of the race. That takes at most 128 steps.
+fn first_winning_hold(time: u128, record: u128) -> u128 {
+    // A distance too large for a `u128` beats every record.
+    let wins = |hold: u128| hold.checked_mul(time - hold).is_none_or(|distance| distance > record);
+    let (mut lo, mut hi) = (0, time / 2);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if wins(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// How many hold times beat `record` in a race lasting `time` ms, in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle6::ways_to_win;
+///
+/// assert_eq!(ways_to_win(7, 9), 4);
+/// assert_eq!(ways_to_win(30, 200), 9);
+/// assert_eq!(ways_to_win(71530, 940200), 71503);
+/// // Holding for 5 of 10 ms exactly ties a record of 25.
+/// assert_eq!(ways_to_win(10, 25), 0);
+/// assert_eq!(ways_to_win(10, 24), 1);
+/// assert_eq!(ways_to_win(1 << 60, 0), (1 << 60) - 1);
+/// // Holding for 2 ms of the longest race already goes further than any record.
+/// assert_eq!(ways_to_win(u128::MAX, u128::MAX), u128::MAX - 3);
+/// ```
+pub fn ways_to_win(time: u128, record: u128) -> u128 {
+    winning_holds(time, record).map_or(0, |(first, last)| last - first + 1)
+}
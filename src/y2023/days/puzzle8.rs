@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::runner::Assumption;
+use crate::utils::dot::{Dot, Shape};
+use crate::utils::lcm;
+
+lazy_static! {
+    static ref TARGET_PAT: Regex = Regex::new("\\((\\w+), (\\w+)\\)").unwrap();
+}
+
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+/// Part 1 walks until it finds `ZZZ`, and part 2 takes the LCM of the steps each ghost needs to
+/// reach its first `..Z` node, which is only the answer if the ghosts keep coming back there and
+/// nowhere else ends in `Z` along the way.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::runner::check_part;
+///
+/// let network = "LR\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n";
+/// assert!(check_part(2023, 8, 2, network).is_ok());
+///
+/// // Back on `11Z` every 2 steps, but on `22Z` in between.
+/// let network = "LR\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (22Z, 22Z)\n22Z = (11Z, 11Z)\n";
+/// let reason = check_part(2023, 8, 2, network).unwrap_err().reason;
+/// assert_eq!(reason, "`11A` is on `22Z` after 3 steps, partway round its cycle through `11Z`");
+///
+/// // On `11Z` after 2 steps and again after 3.
+/// let network = "LR\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11Z, 11B)\n";
+/// let reason = check_part(2023, 8, 2, network).unwrap_err().reason;
+/// assert_eq!(reason, "`11A` is on `11Z` after 3 steps, partway round its cycle through `11Z`");
+/// ```
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        part: 1,
+        description: "`ZZZ` can be reached from `AAA`",
+        check: check_reachable,
+    },
+    Assumption {
+        part: 2,
+        description: "every ghost is on a `..Z` node exactly when it has taken a multiple of the steps to its first one, and always on that one",
+        check: check_cycles,
+    },
+];
+
+fn parse(input: &str) -> (&str, Network<'_>) {
+    let lines = input.split("\n\n").collect_vec();
+
+    let instructions = lines[0];
+
+    let nodes: Network = lines[1]
+        .lines()
+        .map(|l| {
+            if let Some((source, targets)) = l.split('=').map(str::trim).collect_tuple() {
+                let target_matches = TARGET_PAT.captures(targets).unwrap();
+
+                (
+                    source,
+                    (
+                        target_matches.get(1).unwrap().as_str(),
+                        target_matches.get(2).unwrap().as_str(),
+                    ),
+                )
+            } else {
+                unreachable!()
+            }
+        })
+        .collect();
+
+    (instructions, nodes)
+}
+
+/// The edges followed from `AAA` to `ZZZ`, or why `ZZZ` can't be reached.
+fn walk<'a>(instructions: &str, nodes: &Network<'a>) -> Result<Vec<(&'a str, &'a str)>, String> {
+    let steps = steps_to_zzz(instructions, nodes)?;
+    // Every node on the way has been looked up once already, so none of them are missing.
+    let mut curr: &str = "AAA";
+    let walked = instructions
+        .chars()
+        .cycle()
+        .take(steps)
+        .map(|c| {
+            let prev = curr;
+            curr = if c == 'L' { nodes[curr].0 } else { nodes[curr].1 };
+            (prev, curr)
+        })
+        .collect();
+    Ok(walked)
+}
+
+/// Follows the instructions from `start` for `steps` steps, or until `stop` says so.
+/// Returns the steps taken and the node reached.
+fn follow<'a>(
+    instructions: &str,
+    nodes: &Network<'a>,
+    start: &'a str,
+    steps: usize,
+    stop: impl Fn(&str) -> bool,
+) -> Result<(usize, &'a str), String> {
+    let mut curr = start;
+
+    for (taken, c) in instructions.chars().cycle().take(steps).enumerate() {
+        curr = match (c, nodes.get(curr)) {
+            ('L', Some(targets)) => targets.0,
+            ('R', Some(targets)) => targets.1,
+            (_, None) => return Err(format!("`{curr}` has no entry in the network")),
+            _ => return Err(format!("`{c}` is not an instruction")),
+        };
+        if stop(curr) {
+            return Ok((taken + 1, curr));
+        }
+    }
+
+    Ok((steps, curr))
+}
+
+/// The steps from `start` to the first node matching `is_end`, and that node. Gives up once the
+/// walk must be going round in circles.
+fn first_end<'a>(
+    instructions: &str,
+    nodes: &Network<'a>,
+    start: &'a str,
+    is_end: impl Fn(&str) -> bool,
+) -> Result<(usize, &'a str), String> {
+    let limit = (nodes.len() + 1) * instructions.len();
+    match follow(instructions, nodes, start, limit, &is_end)? {
+        (steps, end) if is_end(end) => Ok((steps, end)),
+        _ => Err(format!("the walk from `{start}` never gets there")),
+    }
+}
+
+/// The steps from `AAA` to `ZZZ`, as long as the walk gets there.
+fn steps_to_zzz(instructions: &str, nodes: &Network<'_>) -> Result<usize, String> {
+    if !nodes.contains_key("AAA") {
+        return Err("there is no `AAA`".to_owned());
+    }
+    first_end(instructions, nodes, "AAA", |n| n == "ZZZ").map(|(steps, _)| steps)
+}
+
+fn check_reachable(input: &str) -> Result<(), String> {
+    let (instructions, nodes) = parse(input);
+    steps_to_zzz(instructions, &nodes).map(|_| ())
+}
+
+/// A ghost that reaches its first `..Z` node at the end of a pass over the instructions and is
+/// back there after as many steps again, without passing a `..Z` node in between, repeats itself
+/// from then on.
+fn check_cycles(input: &str) -> Result<(), String> {
+    let (instructions, nodes) = parse(input);
+
+    for start in nodes.keys().filter(|s| s.ends_with('A')).sorted() {
+        let (steps, end) = first_end(instructions, &nodes, start, |n| n.ends_with('Z'))?;
+        if !steps.is_multiple_of(instructions.len()) {
+            return Err(format!(
+                "`{start}` reaches `{end}` after {steps} steps, partway through the instructions"
+            ));
+        }
+        match follow(instructions, &nodes, end, steps, |n| n.ends_with('Z'))? {
+            (taken, again) if taken < steps => {
+                return Err(format!(
+                    "`{start}` is on `{again}` after {} steps, partway round its cycle through `{end}`",
+                    steps + taken
+                ));
+            }
+            (_, again) if again != end => {
+                return Err(format!("`{start}` is on `{again}` instead of `{end}` after {} steps", 2 * steps));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+pub fn part1(input: &str) -> usize {
+    let (instructions, nodes) = parse(input);
+    walk(instructions, &nodes).unwrap_or_else(|e| panic!("{e}")).len()
+}
+
+pub fn part2(input: &str) -> usize {
+    let (instructions, nodes) = parse(input);
+
+    let starting_nodes = nodes
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .cloned()
+        .collect_vec();
+    let mut steps_to_z = vec![];
+
+    for node in starting_nodes {
+        let mut steps = 0;
+        let mut curr = node;
+        'inner: for c in instructions.chars().cycle() {
+            steps += 1;
+            curr = match c {
+                'L' => nodes.get(curr).unwrap().0,
+                'R' => nodes.get(curr).unwrap().1,
+                _ => unreachable!(),
+            };
+            if curr.ends_with('Z') {
+                steps_to_z.push(steps);
+                break 'inner;
+            }
+        }
+    }
+
+    lcm(&steps_to_z)
+}
+
+/// The network as `(name, left index, right index)`, indexed by position in the input.
+fn reference_network(input: &str) -> (Vec<char>, Vec<(&str, usize, usize)>) {
+    let (instructions, network) = input.split_once("\n\n").unwrap();
+    let rows = network
+        .lines()
+        .map(|l| (&l[0..3], &l[7..10], &l[12..15]))
+        .collect_vec();
+    let index = |name: &str| rows.iter().position(|r| r.0 == name).unwrap();
+
+    let nodes = rows.iter().map(|&(name, left, right)| (name, index(left), index(right))).collect();
+    (instructions.trim().chars().collect(), nodes)
+}
+
+/// Walks every node ending in `start` in lockstep until all of them end in `end` at once.
+fn reference_steps(input: &str, start: &str, end: &str, exact: bool) -> usize {
+    let (instructions, nodes) = reference_network(input);
+    let matches = |name: &str, suffix: &str| if exact { name == suffix } else { name.ends_with(suffix) };
+
+    let mut current = (0..nodes.len()).filter(|&n| matches(nodes[n].0, start)).collect_vec();
+    let mut steps = 0;
+    while !current.iter().all(|&n| matches(nodes[n].0, end)) {
+        let turn = instructions[steps % instructions.len()];
+        for n in &mut current {
+            *n = if turn == 'L' { nodes[*n].1 } else { nodes[*n].2 };
+        }
+        steps += 1;
+    }
+    steps
+}
+
+/// Reference for [`part1`], walking from `AAA` one step at a time.
+pub fn reference_part1(input: &str) -> usize {
+    reference_steps(input, "AAA", "ZZZ", true)
+}
+
+/// Reference for [`part2`], walking every ghost together until they all stand on a `..Z` node.
+pub fn reference_part2(input: &str) -> usize {
+    reference_steps(input, "A", "Z", false)
+}
+
+/// The L/R network, with start (`..A`) and end (`..Z`) nodes marked and the part 1 walk highlighted
+/// if there is one.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::days::puzzle8::draw_network;
+///
+/// let dot = draw_network("L\n\nAAA = (ZZZ, AAA)\nZZZ = (ZZZ, ZZZ)\n").to_string();
+/// assert_eq!(dot.matches("penwidth").count(), 1);
+///
+/// // Without an `AAA`, or with a `ZZZ` it never gets to, there is no walk to highlight.
+/// let dot = draw_network("L\n\nBBB = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)\n").to_string();
+/// assert!(dot.contains("\"BBB\" -> \"ZZZ\"") && !dot.contains("penwidth"));
+/// let dot = draw_network("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").to_string();
+/// assert!(!dot.contains("penwidth"));
+/// ```
+pub fn draw_network(input: &str) -> Dot {
+    let (instructions, nodes) = parse(input);
+    let walked = walk(instructions, &nodes).unwrap_or_default();
+    let mut dot = Dot::directed();
+
+    for name in nodes.keys().sorted() {
+        if name.ends_with('A') {
+            dot.node(name, Shape::Box);
+        } else if name.ends_with('Z') {
+            dot.node(name, Shape::DoubleCircle);
+        }
+    }
+
+    for (name, (left, right)) in nodes.iter().sorted() {
+        dot.edge(name, left, Some("L"));
+        dot.edge(name, right, Some("R"));
+    }
+
+    for (from, to) in walked.iter().unique() {
+        dot.highlight(from, to);
+    }
+
+    dot
+}
@@ -0,0 +1,102 @@
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Sequence {
+    seq: Vec<Vec<i64>>,
+}
+
+impl Sequence {
+    fn new(v: Vec<i64>) -> Sequence {
+        let mut start = vec![v];
+        loop {
+            if start.last().unwrap().iter().all(|i| i == &0) {
+                break;
+            }
+
+            start.push(successive_diff(start.last().unwrap()));
+        }
+
+        Sequence { seq: start }
+    }
+
+    fn predict_next_element(&mut self) -> i64 {
+        self.seq.last_mut().unwrap().push(0);
+        for i in (0..=(self.seq.len() - 2)).rev() {
+            let element = self.seq[i].last().unwrap() + self.seq[i + 1].last().unwrap();
+            self.seq[i].push(element);
+        }
+
+        *self.seq[0].last().unwrap()
+    }
+}
+
+fn successive_diff(v: &[i64]) -> Vec<i64> {
+    let mut res = Vec::with_capacity(v.len() - 1);
+    for i in 1..v.len() {
+        res.push(v[i] - v[i - 1]);
+    }
+    res
+}
+
+fn parse(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|l| {
+            l.split_ascii_whitespace()
+                .map(|s| s.parse().unwrap())
+                .collect_vec()
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> i64 {
+    parse(input)
+        .into_iter()
+        .map(|v: Vec<i64>| {
+            let mut s = Sequence::new(v);
+            s.predict_next_element()
+        })
+        .sum()
+}
+
+pub fn part2(input: &str) -> i64 {
+    parse(input)
+        .into_iter()
+        .map(|v: Vec<i64>| {
+            let mut s = Sequence::new(v.iter().rev().copied().collect_vec());
+            s.predict_next_element()
+        })
+        .sum()
+}
+
+fn binomial(n: usize, k: usize) -> i128 {
+    (0..k).fold(1, |acc, i| acc * (n - i) as i128 / (i + 1) as i128)
+}
+
+/// Extrapolates the polynomial through `ys` at `x = ys.len()` using the closed form of the
+/// repeated differences, `p(n) = Σ (-1)^(n-1-i) C(n, i) y_i`.
+fn reference_next(ys: &[i64]) -> i64 {
+    let n = ys.len();
+    let sum: i128 = ys
+        .iter()
+        .enumerate()
+        .map(|(i, &y)| {
+            let sign = if (n - 1 - i).is_multiple_of(2) { 1 } else { -1 };
+            sign * binomial(n, i) * y as i128
+        })
+        .sum();
+    sum as i64
+}
+
+/// Reference for [`part1`], using the binomial closed form instead of difference tables.
+pub fn reference_part1(input: &str) -> i64 {
+    parse(input).iter().map(|ys| reference_next(ys)).sum()
+}
+
+/// Reference for [`part2`], using the binomial closed form on the reversed history.
+pub fn reference_part2(input: &str) -> i64 {
+    parse(input)
+        .iter()
+        .map(|ys| reference_next(&ys.iter().rev().copied().collect_vec()))
+        .sum()
+}
@@ -0,0 +1,1067 @@
+//! Random but valid 2023 puzzle inputs, for stress and performance testing.
+//!
+//! Every generator takes a seeded [`Rng`] and a `size` whose meaning depends on the day (lines,
+//! grid side, node count, ...). Where the real inputs have structure that the puzzle relies on,
+//! such as the counters in day 20 or the rock trajectory in day 24, the generated inputs have it
+//! too.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use crate::utils::rng::Rng;
+
+/// The size used for each day when none is given, roughly matching the real inputs.
+pub const DEFAULT_SIZES: [usize; 25] = [
+    1000, 100, 140, 200, 30, 4, 1000, 60, 200, 140, 140, 1000, 100, 100, 4000, 110, 141, 60, 500, 4, 131,
+    1200, 141, 300, 1500,
+];
+
+/// Generates an input for `day` (1-based), using the day's default size when `size` is `None`.
+/// Returns `None` for days outside 1..=25.
+///
+/// # Examples
+///
+/// ```
+/// use rust_aoc_2023::y2023::generate::generate;
+///
+/// let a = generate(12, 7, Some(5)).unwrap();
+/// assert_eq!(a, generate(12, 7, Some(5)).unwrap());
+/// assert_eq!(a.lines().count(), 5);
+/// assert!(generate(26, 7, None).is_none());
+/// ```
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Option<String> {
+    let generators: [fn(&mut Rng, usize) -> String; 25] = [
+        puzzle1, puzzle2, puzzle3, puzzle4, puzzle5, puzzle6, puzzle7, puzzle8, puzzle9, puzzle10, puzzle11,
+        puzzle12, puzzle13, puzzle14, puzzle15, puzzle16, puzzle17, puzzle18, puzzle19, puzzle20, puzzle21,
+        puzzle22, puzzle23, puzzle24, puzzle25,
+    ];
+
+    let generator = generators.get(day.checked_sub(1)?)?;
+    let size = size.unwrap_or(DEFAULT_SIZES[day - 1]);
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn word(rng: &mut Rng, len: usize, alphabet: &[u8]) -> String {
+    (0..len).map(|_| *rng.choose(alphabet) as char).collect()
+}
+
+/// `count` distinct names made with `make`, none of them in `taken`. The new names are added to
+/// `taken`.
+fn unique_names<F>(rng: &mut Rng, count: usize, taken: &mut HashSet<String>, mut make: F) -> Vec<String>
+where
+    F: FnMut(&mut Rng) -> String,
+{
+    let mut names = vec![];
+    while names.len() < count {
+        let name = make(rng);
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn grid_to_string(grid: &[Vec<char>]) -> String {
+    grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+fn is_prime(n: usize) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+/// Grows a random hole-free polyomino of about `target` cells on a `rows` × `cols` board.
+///
+/// Cells that only touch diagonally are never both included unless they share an orthogonal
+/// neighbour, so the outline of the shape is a simple loop.
+fn polyomino(rng: &mut Rng, rows: usize, cols: usize, target: usize) -> Vec<Vec<bool>> {
+    let mut cells = vec![vec![false; cols]; rows];
+    let mut members = vec![(rows / 2, cols / 2)];
+    cells[rows / 2][cols / 2] = true;
+
+    let pinched = |cells: &[Vec<bool>], r: usize, c: usize| {
+        let at = |r: usize, c: usize| cells.get(r).and_then(|row| row.get(c)).copied().unwrap_or(false);
+        [(r.wrapping_sub(1), c.wrapping_sub(1)), (r.wrapping_sub(1), c), (r, c.wrapping_sub(1)), (r, c)]
+            .into_iter()
+            .any(|(r, c)| {
+                let (tl, tr) = (at(r, c), at(r, c.wrapping_add(1)));
+                let (bl, br) = (at(r.wrapping_add(1), c), at(r.wrapping_add(1), c.wrapping_add(1)));
+                (tl && br && !tr && !bl) || (tr && bl && !tl && !br)
+            })
+    };
+
+    let has_hole = |cells: &[Vec<bool>], filled: usize| {
+        // Flood the outside, on a board padded by one cell on every side.
+        let mut seen = vec![vec![false; cols + 2]; rows + 2];
+        let mut queue = VecDeque::from([(0usize, 0usize)]);
+        seen[0][0] = true;
+        let mut outside = 1;
+        while let Some((r, c)) = queue.pop_front() {
+            for (nr, nc) in [(r + 1, c), (r.wrapping_sub(1), c), (r, c + 1), (r, c.wrapping_sub(1))] {
+                if nr >= rows + 2 || nc >= cols + 2 || seen[nr][nc] {
+                    continue;
+                }
+                let inside_board = (1..=rows).contains(&nr) && (1..=cols).contains(&nc);
+                if inside_board && cells[nr - 1][nc - 1] {
+                    continue;
+                }
+                seen[nr][nc] = true;
+                outside += 1;
+                queue.push_back((nr, nc));
+            }
+        }
+        outside + filled < (rows + 2) * (cols + 2)
+    };
+
+    let mut attempts = 0;
+    while members.len() < target && attempts < target * 50 {
+        attempts += 1;
+        let (r, c) = *rng.choose(&members);
+        let (nr, nc) = *rng.choose(&[(r + 1, c), (r.wrapping_sub(1), c), (r, c + 1), (r, c.wrapping_sub(1))]);
+        if nr >= rows || nc >= cols || cells[nr][nc] {
+            continue;
+        }
+
+        cells[nr][nc] = true;
+        members.push((nr, nc));
+        if pinched(&cells, nr, nc) || has_hole(&cells, members.len()) {
+            cells[nr][nc] = false;
+            members.pop();
+        }
+    }
+
+    cells
+}
+
+/// The outline of a polyomino as lattice points `(row, col)` of cell corners, in clockwise order
+/// with unit steps between consecutive points.
+fn outline(cells: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let filled = |r: usize, c: usize| cells.get(r).and_then(|row| row.get(c)).copied().unwrap_or(false);
+    let mut next = HashMap::new();
+
+    for (r, row) in cells.iter().enumerate() {
+        for (c, _) in row.iter().enumerate().filter(|(_, filled)| **filled) {
+            if !filled(r.wrapping_sub(1), c) {
+                next.insert((r, c), (r, c + 1));
+            }
+            if !filled(r, c + 1) {
+                next.insert((r, c + 1), (r + 1, c + 1));
+            }
+            if !filled(r + 1, c) {
+                next.insert((r + 1, c + 1), (r + 1, c));
+            }
+            if !filled(r, c.wrapping_sub(1)) {
+                next.insert((r + 1, c), (r, c));
+            }
+        }
+    }
+
+    let start = *next.keys().min().unwrap();
+    let mut points = vec![start];
+    let mut curr = next[&start];
+    while curr != start {
+        points.push(curr);
+        curr = next[&curr];
+    }
+    points
+}
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Calibration lines mixing letters, digits and spelled out digits. `size` is the line count.
+pub fn puzzle1(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let tokens = rng.between(2, 8) as usize;
+        let digit_token = rng.below(tokens);
+        for t in 0..tokens {
+            match rng.below(3) {
+                _ if t == digit_token => out.push(char::from(b'1' + rng.below(9) as u8)),
+                0 => out.push(char::from(b'1' + rng.below(9) as u8)),
+                1 => {
+                    let digit = rng.choose(&DIGIT_WORDS);
+                    out.push_str(digit);
+                }
+                _ => {
+                    let len = rng.between(1, 4) as usize;
+                    out.push_str(&word(rng, len, LOWERCASE));
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Cube games. `size` is the number of games.
+pub fn puzzle2(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let reveals = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = vec!["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let count = rng.between(1, 3) as usize;
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.between(1, 20)))
+                    .join(", ")
+            })
+            .join("; ");
+        out.push_str(&format!("Game {id}: {reveals}\n"));
+    }
+    out
+}
+
+/// An engine schematic. `size` is the side of the square grid.
+pub fn puzzle3(rng: &mut Rng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+    let mut grid = vec![vec!['.'; size]; size];
+
+    for row in &mut grid {
+        let mut c = 0;
+        while c < size {
+            let digits = rng.between(1, 3) as usize;
+            if rng.chance(0.15) && c + digits <= size {
+                let number = rng.between(10i64.pow(digits as u32 - 1).max(1), 10i64.pow(digits as u32) - 1);
+                for (i, d) in number.to_string().chars().enumerate() {
+                    row[c + i] = d;
+                }
+                // Keep a gap so the next number does not run into this one.
+                c += digits + 1;
+            } else {
+                if rng.chance(0.08) {
+                    row[c] = *rng.choose(SYMBOLS) as char;
+                }
+                c += 1;
+            }
+        }
+    }
+
+    grid_to_string(&grid)
+}
+
+/// Scratchcards. `size` is the number of cards. Wins never run past the last card, and are
+/// rare enough that the card count in part 2 grows linearly rather than exponentially.
+pub fn puzzle4(rng: &mut Rng, size: usize) -> String {
+    const WINNING: usize = 10;
+    const HAVE: usize = 25;
+    let mut out = String::new();
+
+    for card in 1..=size {
+        let mut numbers = (1..=99).collect_vec();
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(WINNING);
+
+        let matches = if rng.chance(0.7) {
+            0
+        } else if rng.chance(0.75) {
+            rng.between(1, 2) as usize
+        } else {
+            rng.between(3, 8) as usize
+        };
+        let matches = matches.min(size - card);
+
+        let mut have = winning[..matches].to_vec();
+        have.extend_from_slice(&others[..HAVE - matches]);
+        rng.shuffle(&mut have);
+
+        let mut winning = winning.to_vec();
+        rng.shuffle(&mut winning);
+
+        let fmt = |ns: &[usize]| ns.iter().map(|n| format!("{n:>2}")).join(" ");
+        out.push_str(&format!("Card {card:>3}: {} | {}\n", fmt(&winning), fmt(&have)));
+    }
+
+    out
+}
+
+/// An almanac. `size` is the number of ranges in each map, at least 1. Small sizes also keep the
+/// numbers below `16^size`, so that every seed can be walked through the maps one at a time.
+pub fn puzzle5(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    const STAGES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+    let limit: i64 = 1 << (4 * size).min(32);
+    let seeds = (0..5)
+        .map(|_| {
+            let start = rng.between(0, limit - 2);
+            let len = rng.between(1, (limit - start - 1).min(500_000_000));
+            format!("{start} {len}")
+        })
+        .join(" ");
+    let mut out = format!("seeds: {seeds}\n");
+
+    for (from, to) in STAGES.iter().tuple_windows() {
+        let mut cuts = (0..size).map(|_| rng.between(0, limit)).collect_vec();
+        cuts.extend([0, limit]);
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let sources = cuts.iter().tuple_windows().map(|(a, b)| (*a, b - a)).collect_vec();
+        let mut destinations = sources.clone();
+        rng.shuffle(&mut destinations);
+
+        // Lay the shuffled ranges end to end, so the map is a permutation of the covered numbers.
+        let mut offset = 0;
+        let mut ranges = vec![];
+        for &(_, len) in &destinations {
+            ranges.push((offset, len));
+            offset += len;
+        }
+        let mut entries = sources.into_iter().zip(ranges).collect_vec();
+        rng.shuffle(&mut entries);
+
+        out.push_str(&format!("\n{from}-to-{to} map:\n"));
+        for ((src, len), (dest, _)) in entries {
+            if rng.chance(0.9) {
+                out.push_str(&format!("{dest} {src} {len}\n"));
+            }
+        }
+    }
+
+    out
+}
+
+/// Boat races, each of which can be won. `size` is the number of races, at most 8 so that the
+/// part 2 numbers still fit comfortably.
+pub fn puzzle6(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 8);
+    loop {
+        let times = (0..size).map(|_| rng.between(7, 99) as u128).collect_vec();
+        let distances = times
+            .iter()
+            .map(|t| {
+                let best = (t / 2) * (t - t / 2);
+                rng.between(1, best as i64 - 1) as u128
+            })
+            .collect_vec();
+
+        let join = |ns: &[u128]| ns.iter().join("");
+        let time: u128 = join(&times).parse().unwrap();
+        let distance: u128 = join(&distances).parse().unwrap();
+        if (time / 2) * (time - time / 2) <= distance {
+            continue;
+        }
+
+        let row = |ns: &[u128]| ns.iter().map(|n| format!("{n:>5}")).join("");
+        return format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances));
+    }
+}
+
+/// Camel card hands with bids. `size` is the number of hands, at most the number of distinct hands.
+pub fn puzzle7(rng: &mut Rng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+
+    while seen.len() < size.min(CARDS.len().pow(5)) {
+        let mut labels = CARDS.to_vec();
+        rng.shuffle(&mut labels);
+        let kinds = rng.between(1, 5) as usize;
+        let hand = (0..5).map(|_| *rng.choose(&labels[..kinds]) as char).collect::<String>();
+        if seen.insert(hand.clone()) {
+            out.push_str(&format!("{hand} {}\n", rng.between(1, 1000)));
+        }
+    }
+
+    out
+}
+
+/// A desert map. `size` is the length of the instructions, at most 176 so that even the longest
+/// ghost loop fits in the three letter node names.
+///
+/// Like the real inputs, every `..A` node reaches its own `..Z` node after a whole number of
+/// passes over the instructions and then loops back with the same period, so part 2 is an LCM.
+pub fn puzzle8(rng: &mut Rng, size: usize) -> String {
+    const CAPACITY: usize = 26 * 26 * 24 / 2;
+    let mut primes = [3, 5, 7, 11, 13, 17, 19, 23];
+    let size = size.clamp(1, CAPACITY / (2 * primes[primes.len() - 1]));
+    let instructions = (0..size).map(|_| *rng.choose(b"LR") as char).collect::<String>();
+
+    rng.shuffle(&mut primes);
+    let mut ghosts = rng.between(1, 6) as usize;
+    while ghosts > 1 && primes[..ghosts].iter().map(|p| 2 * p * size).sum::<usize>() > CAPACITY {
+        ghosts -= 1;
+    }
+
+    let mut taken = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+    let mut lines = vec![];
+
+    for (g, &prime) in primes[..ghosts].iter().enumerate() {
+        let (start, end) = if g == 0 {
+            ("AAA".to_owned(), "ZZZ".to_owned())
+        } else {
+            let start = unique_names(rng, 1, &mut taken, |rng| word(rng, 2, UPPERCASE) + "A").remove(0);
+            let end = unique_names(rng, 1, &mut taken, |rng| word(rng, 2, UPPERCASE) + "Z").remove(0);
+            (start, end)
+        };
+
+        // Layers 1..period hold two nodes, one reached by `L` and one by `R`. Both lead on to the
+        // next layer, so which one is visited depends on the instructions but the layer does not.
+        let period = size * prime;
+        let inner = unique_names(rng, 2 * (period - 1), &mut taken, |rng| {
+            word(rng, 2, UPPERCASE) + &(*rng.choose(&UPPERCASE[1..25]) as char).to_string()
+        });
+        let layers = inner.chunks(2).collect_vec();
+
+        lines.push(format!("{start} = ({}, {})", layers[0][0], layers[0][1]));
+        lines.push(format!("{end} = ({}, {})", layers[0][0], layers[0][1]));
+        for (layer, next) in layers.iter().zip(layers.iter().skip(1)) {
+            for node in *layer {
+                lines.push(format!("{node} = ({}, {})", next[0], next[1]));
+            }
+        }
+        for node in layers[layers.len() - 1] {
+            lines.push(format!("{node} = ({end}, {end})"));
+        }
+    }
+
+    rng.shuffle(&mut lines);
+    format!("{instructions}\n\n{}\n", lines.join("\n"))
+}
+
+/// OASIS histories, each a polynomial of degree at most 4. `size` is the number of histories.
+pub fn puzzle9(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let coefficients = (0..=rng.between(0, 4)).map(|_| rng.between(-3, 3)).collect_vec();
+        let offset = rng.between(-5, 5);
+        let values = (0..21)
+            .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * (x + offset) + c))
+            .join(" ");
+        out.push_str(&values);
+        out.push('\n');
+    }
+    out
+}
+
+/// A pipe maze with a single loop through `S` and junk pipes everywhere else. `size` is the side
+/// of the square grid.
+pub fn puzzle10(rng: &mut Rng, size: usize) -> String {
+    let coarse = (size.max(3) - 1) / 2;
+    let shape = polyomino(rng, coarse, coarse, coarse * coarse * 2 / 5);
+    let corners = outline(&shape);
+
+    // Corners land on even cells of the fine grid, with the step between them on the odd cell.
+    let mut path = vec![];
+    for (&(r1, c1), &(r2, c2)) in corners.iter().circular_tuple_windows() {
+        path.push((2 * r1, 2 * c1));
+        path.push((r1 + r2, c1 + c2));
+    }
+
+    let side = 2 * coarse + 1;
+    let mut grid = (0..side)
+        .map(|_| (0..side).map(|_| if rng.chance(0.4) { '.' } else { *rng.choose(b"|-LJ7F") as char }).collect_vec())
+        .collect_vec();
+
+    for (prev, curr, next) in path.iter().circular_tuple_windows() {
+        let dir = |to: &(usize, usize)| (to.0 as i64 - curr.0 as i64, to.1 as i64 - curr.1 as i64);
+        let pipe = match [dir(prev), dir(next)].iter().sorted().collect_tuple().unwrap() {
+            ((-1, 0), (1, 0)) => '|',
+            ((0, -1), (0, 1)) => '-',
+            ((-1, 0), (0, 1)) => 'L',
+            ((-1, 0), (0, -1)) => 'J',
+            ((0, -1), (1, 0)) => '7',
+            ((0, 1), (1, 0)) => 'F',
+            _ => unreachable!(),
+        };
+        grid[curr.0][curr.1] = pipe;
+    }
+
+    // Only the two loop pipes next to `S` may connect to it.
+    let loop_cells: HashSet<(usize, usize)> = path.iter().copied().collect();
+    let start = *rng.choose(&path);
+    grid[start.0][start.1] = 'S';
+    for (r, c) in [(start.0 + 1, start.1), (start.0.wrapping_sub(1), start.1), (start.0, start.1 + 1), (start.0, start.1.wrapping_sub(1))] {
+        if r < side && c < side && !loop_cells.contains(&(r, c)) {
+            grid[r][c] = '.';
+        }
+    }
+
+    grid_to_string(&grid)
+}
+
+/// An image of galaxies with some empty rows and columns. `size` is the side of the square grid.
+pub fn puzzle11(rng: &mut Rng, size: usize) -> String {
+    let empty_rows = (0..size).filter(|_| rng.chance(0.1)).collect::<HashSet<_>>();
+    let empty_cols = (0..size).filter(|_| rng.chance(0.1)).collect::<HashSet<_>>();
+
+    let grid = (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| {
+                    let can_place = !empty_rows.contains(&r) && !empty_cols.contains(&c);
+                    if can_place && rng.chance(0.04) { '#' } else { '.' }
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    grid_to_string(&grid)
+}
+
+/// Rows of damaged springs with their group sizes, each with at least one valid arrangement.
+/// `size` is the number of rows.
+pub fn puzzle12(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let mut count = 0;
+    while count < size {
+        let groups = (0..rng.between(1, 6)).map(|_| rng.between(1, 6) as usize).collect_vec();
+        let mut springs = ".".repeat(rng.below(3));
+        for (i, g) in groups.iter().enumerate() {
+            if i > 0 {
+                springs.push_str(&".".repeat(rng.between(1, 3) as usize));
+            }
+            springs.push_str(&"#".repeat(*g));
+        }
+        springs.push_str(&".".repeat(rng.below(3)));
+        if springs.len() > 20 {
+            continue;
+        }
+
+        let masked = springs.chars().map(|c| if rng.chance(0.4) { '?' } else { c }).collect::<String>();
+        out.push_str(&format!("{masked} {}\n", groups.iter().join(",")));
+        count += 1;
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mirror {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+/// Cell pairs that must match for a reflection at `mirror`.
+fn mirror_pairs(mirror: Mirror, rows: usize, cols: usize) -> Vec<((usize, usize), (usize, usize))> {
+    let mut pairs = vec![];
+    match mirror {
+        Mirror::Vertical(c) => {
+            for k in 0..c.min(cols - c) {
+                pairs.extend((0..rows).map(|r| ((r, c - 1 - k), (r, c + k))));
+            }
+        }
+        Mirror::Horizontal(r) => {
+            for k in 0..r.min(rows - r) {
+                pairs.extend((0..cols).map(|c| ((r - 1 - k, c), (r + k, c))));
+            }
+        }
+    }
+    pairs
+}
+
+/// Union-find lookup with path compression.
+fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        parent[i] = find(parent, parent[i]);
+    }
+    parent[i]
+}
+
+/// Patterns of ash and rocks with exactly one reflection, and exactly one other reflection that
+/// is off by a single smudge. `size` is the number of patterns.
+pub fn puzzle13(rng: &mut Rng, size: usize) -> String {
+    let mut patterns = vec![];
+
+    while patterns.len() < size {
+        let (rows, cols) = (rng.between(5, 17) as usize, rng.between(5, 17) as usize);
+        let transpose = rng.chance(0.5);
+
+        // The clean reflection is vertical and off centre, the smudged one horizontal, and the
+        // pattern is transposed half of the time.
+        let column = rng.between(1, cols as i64 - 1) as usize;
+        if 2 * column.min(cols - column) == cols {
+            continue;
+        }
+        let clean = Mirror::Vertical(column);
+        let smudged = Mirror::Horizontal(rng.between(1, rows as i64 - 1) as usize);
+
+        let mut parent = (0..rows * cols).collect_vec();
+        for (a, b) in mirror_pairs(clean, rows, cols).into_iter().chain(mirror_pairs(smudged, rows, cols)) {
+            let (ra, rb) = (find(&mut parent, a.0 * cols + a.1), find(&mut parent, b.0 * cols + b.1));
+            parent[ra] = rb;
+        }
+
+        let colors = (0..rows * cols).map(|_| rng.chance(0.5)).collect_vec();
+        let mut grid = (0..rows)
+            .map(|r| (0..cols).map(|c| colors[find(&mut parent, r * cols + c)]).collect_vec())
+            .collect_vec();
+
+        // Flip a cell that the smudged reflection covers and the clean one does not.
+        let reach = column.min(cols - column);
+        let outside = (0..cols).filter(|c| *c + reach < column || *c >= column + reach).collect_vec();
+        let Mirror::Horizontal(row) = smudged else { unreachable!() };
+        let row_reach = row.min(rows - row);
+        let r = rng.between((row - row_reach) as i64, (row + row_reach) as i64 - 1) as usize;
+        let c = *rng.choose(&outside);
+        grid[r][c] = !grid[r][c];
+
+        let mismatches = |mirror: Mirror| {
+            mirror_pairs(mirror, rows, cols)
+                .into_iter()
+                .filter(|(a, b)| grid[a.0][a.1] != grid[b.0][b.1])
+                .count()
+        };
+        let mirrors = (1..cols).map(Mirror::Vertical).chain((1..rows).map(Mirror::Horizontal)).collect_vec();
+        let exact = mirrors.iter().filter(|m| mismatches(**m) == 0).collect_vec();
+        let off_by_one = mirrors.iter().filter(|m| mismatches(**m) == 1).collect_vec();
+        if exact != [&clean] || off_by_one != [&smudged] {
+            continue;
+        }
+
+        let text = if transpose {
+            (0..cols).map(|c| (0..rows).map(|r| if grid[r][c] { '#' } else { '.' }).collect::<String>()).join("\n")
+        } else {
+            grid.iter().map(|row| row.iter().map(|b| if *b { '#' } else { '.' }).collect::<String>()).join("\n")
+        };
+        patterns.push(text);
+    }
+
+    patterns.join("\n\n") + "\n"
+}
+
+/// A platform of round and cube rocks. `size` is the side of the square grid.
+pub fn puzzle14(rng: &mut Rng, size: usize) -> String {
+    let grid = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.below(20) {
+                    0..=3 => 'O',
+                    4..=6 => '#',
+                    _ => '.',
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    grid_to_string(&grid)
+}
+
+/// An initialization sequence on one line, without a trailing newline. `size` is the number of steps.
+pub fn puzzle15(rng: &mut Rng, size: usize) -> String {
+    let mut taken = HashSet::new();
+    let labels = unique_names(rng, (size / 5).max(1), &mut taken, |rng| {
+        let len = rng.between(2, 6) as usize;
+        word(rng, len, LOWERCASE)
+    });
+
+    (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.35) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.between(1, 9))
+            }
+        })
+        .join(",")
+}
+
+/// A contraption of mirrors and splitters. `size` is the side of the square grid.
+pub fn puzzle16(rng: &mut Rng, size: usize) -> String {
+    let grid = (0..size)
+        .map(|_| (0..size).map(|_| if rng.chance(0.12) { *rng.choose(b"/\\|-") as char } else { '.' }).collect_vec())
+        .collect_vec();
+    grid_to_string(&grid)
+}
+
+/// A heat loss map. `size` is the side of the square grid.
+pub fn puzzle17(rng: &mut Rng, size: usize) -> String {
+    let grid = (0..size)
+        .map(|_| (0..size).map(|_| char::from(b'1' + rng.below(9) as u8)).collect_vec())
+        .collect_vec();
+    grid_to_string(&grid)
+}
+
+/// The clockwise turning points of a polyomino outline, with rows and columns stretched by the
+/// given sizes.
+fn stretched_outline(cells: &[Vec<bool>], heights: &[i64], widths: &[i64]) -> Vec<(i64, i64)> {
+    let prefix = |sizes: &[i64]| {
+        std::iter::once(0)
+            .chain(sizes.iter().scan(0, |acc, s| {
+                *acc += s;
+                Some(*acc)
+            }))
+            .collect_vec()
+    };
+    let (ys, xs) = (prefix(heights), prefix(widths));
+
+    let corners = outline(cells);
+    corners
+        .iter()
+        .circular_tuple_windows()
+        .filter(|(prev, _, next)| prev.0 != next.0 && prev.1 != next.1)
+        .map(|(_, curr, _)| (ys[curr.0], xs[curr.1]))
+        .collect_vec()
+}
+
+/// A dig plan whose trench is a simple loop, for both the direction/length and the colour
+/// reading. `size` is the side of the board the loop is drawn on.
+pub fn puzzle18(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let shape = polyomino(rng, size, size, size * size * 2 / 5);
+    let transposed = (0..size).map(|c| (0..size).map(|r| shape[r][c]).collect_vec()).collect_vec();
+
+    let mut sizes = |count: usize, max: i64| (0..count).map(|_| rng.between(1, max)).collect_vec();
+    let part1 = stretched_outline(&shape, &sizes(size, 8), &sizes(size, 8));
+    let max = (0xfffff / size as i64).max(1);
+    let part2 = stretched_outline(&transposed, &sizes(size, max), &sizes(size, max));
+
+    let steps = |points: &[(i64, i64)]| {
+        points
+            .iter()
+            .circular_tuple_windows()
+            .map(|(a, b)| match (b.0 - a.0, b.1 - a.1) {
+                (0, d) if d > 0 => ('R', d),
+                (d, 0) if d > 0 => ('D', d),
+                (0, d) => ('L', -d),
+                (d, _) => ('U', -d),
+            })
+            .collect_vec()
+    };
+
+    steps(&part1)
+        .into_iter()
+        .zip(steps(&part2))
+        .map(|((dir, len), (hex_dir, hex_len))| {
+            let code = "RDLU".find(hex_dir).unwrap();
+            format!("{dir} {len} (#{hex_len:05x}{code})\n")
+        })
+        .collect()
+}
+
+/// A tree of workflows and a list of parts. `size` is both the number of workflows and of parts,
+/// at least 5 so that there are enough workflows for every rule of `in`.
+pub fn puzzle19(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut taken = HashSet::from(["in".to_owned()]);
+    let mut queue = VecDeque::from(["in".to_owned()]);
+    let mut created = 1;
+    let mut workflows = vec![];
+
+    // Like the real inputs, `in` never accepts or rejects directly.
+    let mut target = |rng: &mut Rng, created: &mut usize, queue: &mut VecDeque<String>, root: bool| {
+        if *created < size && (root || rng.chance(0.6)) {
+            *created += 1;
+            let name = unique_names(rng, 1, &mut taken, |rng| {
+                let len = rng.between(2, 3) as usize;
+                word(rng, len, LOWERCASE)
+            })
+            .remove(0);
+            queue.push_back(name.clone());
+            name
+        } else {
+            rng.choose(&["A", "R"]).to_string()
+        }
+    };
+
+    while let Some(name) = queue.pop_front() {
+        let rules = (0..rng.between(1, 3))
+            .map(|_| {
+                let quality = *rng.choose(&["x", "m", "a", "s"]);
+                let op = *rng.choose(&["<", ">"]);
+                let threshold = rng.between(10, 3990);
+                format!("{quality}{op}{threshold}:{}", target(rng, &mut created, &mut queue, name == "in"))
+            })
+            .collect_vec();
+        let fallback = target(rng, &mut created, &mut queue, name == "in");
+        workflows.push(format!("{name}{{{},{fallback}}}", rules.join(",")));
+    }
+
+    rng.shuffle(&mut workflows);
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.between(1, 4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .join("\n");
+
+    format!("{}\n\n{parts}\n", workflows.join("\n"))
+}
+
+/// A module configuration built like the real inputs: the broadcaster drives `size` 12 bit
+/// counters, each resetting at a different prime and signalling `rx` through a chain of
+/// conjunctions.
+pub fn puzzle20(rng: &mut Rng, size: usize) -> String {
+    const BITS: usize = 12;
+    let size = size.max(1);
+    let count = size * (BITS + 2) + 1;
+    let len = if count < 600 { 2 } else { 3 };
+    let mut taken = HashSet::from(["rx".to_owned()]);
+    let mut names = unique_names(rng, count, &mut taken, |rng| word(rng, len, LOWERCASE));
+    let last = names.pop().unwrap();
+
+    let primes = (1 << (BITS - 1)..1 << BITS).filter(|n| is_prime(*n)).collect_vec();
+    let mut lines = vec![];
+    let mut starts = vec![];
+
+    for counter in names.chunks(BITS + 2) {
+        let (flip_flops, hub, inverter) = (&counter[..BITS], &counter[BITS], &counter[BITS + 1]);
+        let period = *rng.choose(&primes);
+        starts.push(flip_flops[0].clone());
+
+        let mut hub_outputs = vec![];
+        for (bit, ff) in flip_flops.iter().enumerate() {
+            let mut outputs = vec![];
+            if bit + 1 < BITS {
+                outputs.push(flip_flops[bit + 1].clone());
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            }
+            if period & (1 << bit) == 0 || bit == 0 {
+                hub_outputs.push(ff.clone());
+            }
+            rng.shuffle(&mut outputs);
+            lines.push(format!("%{ff} -> {}", outputs.join(", ")));
+        }
+        hub_outputs.push(inverter.clone());
+        rng.shuffle(&mut hub_outputs);
+
+        lines.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+    }
+
+    lines.push(format!("&{last} -> rx"));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// A garden with the same features as the real inputs: `S` in the middle of an odd sized square,
+/// with the middle row and column, the border and a diamond band free of rocks. `size` is the side
+/// of the grid, rounded up to an odd number.
+pub fn puzzle21(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mid = size / 2;
+
+    let grid = (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| {
+                    let diamond = r.abs_diff(mid) + c.abs_diff(mid);
+                    let clear = r == mid || c == mid || r == 0 || c == 0 || r == size - 1 || c == size - 1;
+                    let band = diamond.abs_diff(mid) <= 1;
+                    if r == mid && c == mid {
+                        'S'
+                    } else if !clear && !band && rng.chance(0.12) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    grid_to_string(&grid)
+}
+
+/// A snapshot of falling bricks that do not overlap. `size` is the number of bricks.
+pub fn puzzle22(rng: &mut Rng, size: usize) -> String {
+    let side = if size >= 50 { 10 } else { 3 };
+    let mut heights = vec![vec![0; side]; side];
+    let mut bricks = vec![];
+
+    for _ in 0..size {
+        let len = rng.between(1, 4) as usize;
+        let (x1, y1) = (rng.below(side), rng.below(side));
+        let (mut x2, mut y2, mut dz) = (x1, y1, 0);
+        match rng.below(3) {
+            0 => x2 = (x1 + len - 1).min(side - 1),
+            1 => y2 = (y1 + len - 1).min(side - 1),
+            _ => dz = len - 1,
+        }
+
+        // Start above everything below the footprint, so bricks never overlap.
+        let floor = heights[x1..=x2].iter().flat_map(|row| &row[y1..=y2]).max().unwrap();
+        let z = floor + 1 + rng.below(3);
+        for row in &mut heights[x1..=x2] {
+            for h in &mut row[y1..=y2] {
+                *h = z + dz;
+            }
+        }
+        bricks.push(format!("{x1},{y1},{z}~{x2},{y2},{}", z + dz));
+    }
+
+    rng.shuffle(&mut bricks);
+    bricks.join("\n") + "\n"
+}
+
+/// A hiking map built like the real inputs: a lattice of at most 5 × 5 junctions joined by
+/// corridors that slope down and to the right, between a start on the top row and an end on the
+/// bottom row. `size` is roughly the side of the square grid.
+pub fn puzzle23(rng: &mut Rng, size: usize) -> String {
+    let count = ((size.max(7) - 1) / 3 - 1).min(5);
+    let spacing = (size.max(7) - 1) / (count + 1);
+    let side = (count + 1) * spacing + 1;
+    let junction = |i: usize| (i + 1) * spacing;
+
+    // Drop some corridors, as long as the end can still be reached from the start.
+    let mut right = vec![vec![true; count]; count];
+    let mut down = vec![vec![true; count]; count];
+    let reachable = |right: &[Vec<bool>], down: &[Vec<bool>]| {
+        let mut seen = vec![vec![false; count]; count];
+        seen[0][0] = true;
+        for i in 0..count {
+            for j in 0..count {
+                if seen[i][j] {
+                    if j + 1 < count && right[i][j] {
+                        seen[i][j + 1] = true;
+                    }
+                    if i + 1 < count && down[i][j] {
+                        seen[i + 1][j] = true;
+                    }
+                }
+            }
+        }
+        seen[count - 1][count - 1]
+    };
+    for i in 0..count {
+        for j in 0..count {
+            if j + 1 < count && rng.chance(0.15) {
+                right[i][j] = false;
+                right[i][j] = !reachable(&right, &down);
+            }
+            if i + 1 < count && rng.chance(0.15) {
+                down[i][j] = false;
+                down[i][j] = !reachable(&right, &down);
+            }
+        }
+    }
+
+    let mut grid = vec![vec!['#'; side]; side];
+    let first = junction(0);
+    let last = junction(count - 1);
+
+    // From the start down to the first junction's row, then across to it.
+    for row in &mut grid[..=first] {
+        row[1] = '.';
+    }
+    grid[first][1..first].fill('.');
+    grid[first][first - 1] = '>';
+    // From the last junction across to the second to last column, then down to the end.
+    grid[last][last + 1..side - 1].fill('.');
+    grid[last][last + 1] = '>';
+    for row in &mut grid[last..] {
+        row[side - 2] = '.';
+    }
+
+    for i in 0..count {
+        for j in 0..count {
+            let (r, c) = (junction(i), junction(j));
+            grid[r][c] = '.';
+            if j + 1 < count && right[i][j] {
+                grid[r][c + 1..junction(j + 1)].fill('.');
+                grid[r][c + 1] = '>';
+                grid[r][junction(j + 1) - 1] = '>';
+            }
+            if i + 1 < count && down[i][j] {
+                for row in &mut grid[r + 1..junction(i + 1)] {
+                    row[c] = '.';
+                }
+                grid[r + 1][c] = 'v';
+                grid[junction(i + 1) - 1][c] = 'v';
+            }
+        }
+    }
+
+    grid_to_string(&grid)
+}
+
+/// Hailstones on the scale of the real inputs, all of which are hit by a single rock thrown
+/// from an integer position with an integer velocity. `size` is the number of hailstones, at
+/// least the three needed to pin down the rock.
+pub fn puzzle24(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let rock = [(); 3].map(|_| rng.between(200_000_000_000_000, 400_000_000_000_000));
+    let rock_velocity = [(); 3].map(|_| rng.between(-300, 300));
+
+    let mut times = HashSet::new();
+    let mut out = String::new();
+    while times.len() < size {
+        // Collision times are short enough to keep the hailstones near the part 1 test area.
+        let t = rng.between(10_000_000_000, 200_000_000_000);
+        if !times.insert(t) {
+            continue;
+        }
+
+        let velocity = rock_velocity.map(|v| loop {
+            let candidate = rng.between(-400, 400);
+            if candidate != 0 && candidate != v {
+                break candidate;
+            }
+        });
+        let position = [0, 1, 2].map(|i| rock[i] + (rock_velocity[i] - velocity[i]) * t);
+
+        out.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        ));
+    }
+    out
+}
+
+/// Two well connected components joined by exactly three wires. `size` is the number of components.
+pub fn puzzle25(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(16);
+    let mut taken = HashSet::new();
+    let names = unique_names(rng, size, &mut taken, |rng| word(rng, 3, LOWERCASE));
+    let split = rng.between(size as i64 * 2 / 5, size as i64 * 3 / 5) as usize;
+
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    let mut degree = vec![0; size];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && edges.insert((a.min(b), a.max(b))) {
+            degree[a] += 1;
+            degree[b] += 1;
+        }
+    };
+
+    for (lo, hi) in [(0, split), (split, size)] {
+        // A ring keeps each side connected, random chords make it well connected.
+        for i in lo..hi {
+            connect(i, if i + 1 == hi { lo } else { i + 1 });
+            for _ in 0..2 {
+                connect(i, rng.between(lo as i64, hi as i64 - 1) as usize);
+            }
+        }
+    }
+
+    let mut left = (0..split).collect_vec();
+    let mut right = (split..size).collect_vec();
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    for (a, b) in left.into_iter().zip(right).take(3) {
+        connect(a, b);
+    }
+
+    // A node with three or fewer wires would be a cut of its own.
+    for i in 0..size {
+        let (lo, hi) = if i < split { (0, split) } else { (split, size) };
+        while degree[i] < 4 {
+            let j = rng.between(lo as i64, hi as i64 - 1) as usize;
+            if i != j && edges.insert((i.min(j), i.max(j))) {
+                degree[i] += 1;
+                degree[j] += 1;
+            }
+        }
+    }
+
+    let mut listed: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (a, b) in edges.into_iter().sorted() {
+        let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        listed.entry(from).or_default().push(to);
+    }
+
+    let mut lines = listed
+        .into_iter()
+        .sorted()
+        .map(|(from, tos)| format!("{}: {}", names[from], tos.iter().map(|t| &names[*t]).join(" ")))
+        .collect_vec();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
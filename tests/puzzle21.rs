@@ -0,0 +1,37 @@
+//! The day 21 extrapolation, checked against walking the repeated garden for a few gardens' worth
+//! of steps. The 26501365 steps of part 2 are out of reach for the walk.
+
+use rust_aoc_2023::runner::check_part;
+use rust_aoc_2023::y2023::days::puzzle21::{reachable_after, reference_reachable, PART2_STEPS};
+use rust_aoc_2023::y2023::generate::generate;
+
+#[test]
+fn reachable_after_matches_walking_the_garden() {
+    for size in [5, 7, 9, 11] {
+        for seed in 0..5 {
+            let input = generate(21, seed, Some(size)).unwrap();
+            assert_eq!(check_part(2023, 21, 2, &input), Ok(()), "size {size}, seed {seed}");
+
+            // Part 2 walks `PART2_STEPS % side` steps and then a whole number of gardens, so the
+            // same offset is used here. The first three are where the quadratic comes from.
+            let offset = PART2_STEPS % size;
+            for n in 0..7 {
+                let steps = offset + n * size;
+                assert_eq!(
+                    reachable_after(&input, steps),
+                    reference_reachable(&input, steps),
+                    "size {size}, seed {seed}, {steps} steps\n{input}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn reference_reachable_matches_the_puzzle_example() {
+    let garden = "...........\n.....###.#.\n.###.##..#.\n..#.#...#..\n....#.#....\n.##..S####.\n\
+                  .##..#...#.\n.......##..\n.##.#.####.\n.##..##.##.\n...........\n";
+    for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+        assert_eq!(reference_reachable(garden, steps), plots, "{steps} steps");
+    }
+}
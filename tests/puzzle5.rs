@@ -0,0 +1,125 @@
+//! Range mapping in the day 5 almanac, checked number by number against single lookups for every
+//! small map and range.
+
+use rust_aoc_2023::utils::interval::Interval;
+use rust_aoc_2023::y2023::days::puzzle5::{PiecewiseMap, RangedMap, RangedMapList};
+
+/// Every map line with its numbers in a small window, so overlaps and gaps of every shape occur.
+fn lines() -> Vec<(i64, i64, i64)> {
+    let mut lines = vec![];
+    for source_start in 0..6 {
+        for range in 1..4 {
+            for destination_start in 0..5 {
+                lines.push((source_start, destination_start, range));
+            }
+        }
+    }
+    lines
+}
+
+fn list(lines: &[(i64, i64, i64)]) -> RangedMapList {
+    RangedMapList::new_sorted(
+        lines
+            .iter()
+            .map(|&(source_start, destination_start, range)| RangedMap {
+                source_start,
+                destination_start,
+                range,
+            })
+            .collect(),
+    )
+}
+
+/// Checks that `map` agrees with `list` and that mapping each range of `0..10` at once, through
+/// either of them, maps each of its numbers exactly once.
+fn check_image(list: &RangedMapList, map: &PiecewiseMap) {
+    for x in -2..12 {
+        assert_eq!(map.get(x), Some(list.get(x)), "{map:?} at {x}");
+    }
+
+    for start in 0..10 {
+        for end in start..=10 {
+            let range = Interval::new(start, end);
+            let image = map.image(range);
+            assert!(image.iter().all(|i| !i.is_empty()), "{range:?} mapped to {image:?}");
+            assert_eq!(list.get_ranged(range), image, "{range:?} through {list:?}");
+
+            let mut got: Vec<i64> = image.iter().flat_map(|i| i.start..i.end).collect();
+            let mut expected: Vec<i64> = (start..end).map(|x| list.get(x)).collect();
+            got.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(got, expected, "{range:?} mapped to {image:?}");
+            assert_eq!(map.min_image(range), expected.first().copied());
+        }
+    }
+}
+
+/// Checks that the inverse of `map` takes every number to its smallest preimage, if it has one.
+/// Lines only move numbers within `0..10`, so preimages of `-2..12` are all in `-2..12`.
+fn check_inverse(map: &PiecewiseMap) {
+    let inverse = map.inverse();
+    for y in -2..12 {
+        let smallest = (-2..12).find(|&x| map.get(x) == Some(y));
+        assert_eq!(inverse.get(y), smallest, "{map:?} back from {y}");
+    }
+}
+
+#[test]
+fn image_matches_get_without_lines() {
+    let list = list(&[]);
+    check_image(&list, &PiecewiseMap::from(&list));
+}
+
+#[test]
+fn image_and_inverse_match_get_with_one_line() {
+    for line in lines() {
+        let list = list(&[line]);
+        let map = PiecewiseMap::from(&list);
+        check_image(&list, &map);
+        check_inverse(&map);
+    }
+}
+
+#[test]
+fn image_and_inverse_match_get_with_two_lines() {
+    let lines = lines();
+    for a in &lines {
+        for b in &lines {
+            let list = list(&[*a, *b]);
+            let map = PiecewiseMap::from(&list);
+            check_image(&list, &map);
+            check_inverse(&map);
+        }
+    }
+}
+
+#[test]
+fn compose_matches_get_one_after_the_other() {
+    let lines = lines();
+    for a in &lines {
+        for b in &lines {
+            let (first, then) = (list(&[*a]), list(&[*b]));
+            let composed = PiecewiseMap::from(&first).compose(&PiecewiseMap::from(&then));
+            for x in -2..12 {
+                assert_eq!(composed.get(x), Some(then.get(first.get(x))), "{a:?} then {b:?} at {x}");
+            }
+            check_inverse(&composed);
+        }
+    }
+}
+
+#[test]
+fn image_keeps_single_number_tails() {
+    let map = PiecewiseMap::from(&list(&[(0, 10, 3)]));
+    assert_eq!(map.image(Interval::new(0, 4)), [Interval::new(10, 13), Interval::new(3, 4)]);
+    assert_eq!(map.image(Interval::new(3, 4)), [Interval::new(3, 4)]);
+}
+
+#[test]
+fn image_keeps_gaps_their_length() {
+    let map = PiecewiseMap::from(&list(&[(5, 20, 2)]));
+    assert_eq!(
+        map.image(Interval::new(2, 9)),
+        [Interval::new(2, 5), Interval::new(20, 22), Interval::new(7, 9)]
+    );
+}
@@ -0,0 +1,76 @@
+//! The closed forms for the day 6 boat races, checked against trying every hold time.
+
+use std::ops::RangeInclusive;
+
+use rust_aoc_2023::y2023::days::puzzle6::{ways_to_win, Race, Speed};
+
+fn tried(time: u128, record: u128) -> u128 {
+    (0..=time).filter(|hold| hold * (time - hold) > record).count() as u128
+}
+
+#[test]
+fn ways_to_win_matches_trying_every_hold() {
+    for time in 0..120 {
+        // Up to just past the best distance, so every tie and every unbeatable record comes up.
+        for record in 0..=time * time / 4 + 1 {
+            assert_eq!(ways_to_win(time, record), tried(time, record), "time {time}, record {record}");
+        }
+    }
+}
+
+#[test]
+fn ways_to_win_handles_long_races() {
+    let time = (1u128 << 63) + 1;
+    assert_eq!(ways_to_win(time, 0), time - 1);
+    // The best holds are 2^62 and 2^62 + 1, which tie this record.
+    assert_eq!(ways_to_win(time, (1 << 62) * ((1 << 62) + 1)), 0);
+    assert_eq!(ways_to_win(time, (1 << 62) * ((1 << 62) + 1) - 1), 2);
+}
+
+#[test]
+fn ways_to_win_handles_races_too_long_to_square() {
+    // Whether holding for `hold` ms wins, counting a distance past `u128::MAX` as a win.
+    let wins = |time: u128, record: u128, hold: u128| hold.checked_mul(time - hold).is_none_or(|d| d > record);
+    for time in [1u128 << 65, (1 << 65) + 1, (1 << 100) + 7, u128::MAX - 1, u128::MAX] {
+        for record in [0, 1 << 64, (1 << 127) + 3, u128::MAX - 1, u128::MAX] {
+            // Holding for half the race goes past `u128::MAX`, so some hold always wins.
+            let ways = ways_to_win(time, record);
+            assert!(ways > 0, "time {time}, record {record}");
+            // The winning holds are symmetric around the middle of the race, so the first one
+            // follows from their number.
+            let first = time / 2 - (ways - 1) / 2;
+            assert!(wins(time, record, first), "time {time}, record {record}");
+            assert!(first == 0 || !wins(time, record, first - 1), "time {time}, record {record}");
+        }
+    }
+}
+
+/// The winning holds of `race` found one hold time at a time, as ranges.
+fn tried_holds(race: Race, speed: Speed) -> Vec<RangeInclusive<u128>> {
+    let mut ranges: Vec<RangeInclusive<u128>> = vec![];
+    for hold in (0..=race.time).filter(|&hold| speed.after(hold) * (race.time - hold) > race.record) {
+        match ranges.last_mut() {
+            Some(last) if last.end() + 1 == hold => *last = *last.start()..=hold,
+            _ => ranges.push(hold..=hold),
+        }
+    }
+    ranges
+}
+
+#[test]
+fn winning_holds_match_trying_every_hold() {
+    for time in 0..30 {
+        for record in 0..=time * time / 2 + 1 {
+            let race = Race { time, record };
+            for per_ms in 0..4 {
+                let linear = Speed::Linear { per_ms };
+                assert_eq!(race.winning_holds(linear), tried_holds(race, linear), "{race:?}, {linear:?}");
+
+                for max in 0..2 * time {
+                    let capped = Speed::Capped { per_ms, max };
+                    assert_eq!(race.winning_holds(capped), tried_holds(race, capped), "{race:?}, {capped:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! Properties of the grid, number, interval, text and drawing helpers in `utils`, over random
+//! inputs rather than the single examples in their doctests.
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use rust_aoc_2023::utils::automaton::{Automaton, Match};
+use rust_aoc_2023::utils::interval::Interval;
+use rust_aoc_2023::utils::render::{Color, GridRenderer};
+use rust_aoc_2023::utils::svg::Svg;
+use rust_aoc_2023::utils::{gcd, iter_neighbors4, iter_neighbors4_bounded, iter_neighbors8, iter_neighbors_bounded8, lcm};
+
+/// Coordinates well away from overflow, on both sides of 0.
+const COORD: std::ops::Range<i32> = -1000..1000;
+
+/// Interval ends in a window small enough to check every number in it.
+const END: std::ops::Range<i64> = -20..20;
+
+/// Slack for coordinates that went through the rounding of the SVG output.
+const EPSILON: f64 = 1e-4;
+
+/// The value of every `name="..."` attribute in `svg`, in order.
+fn attributes<'a>(svg: &'a str, name: &str) -> Vec<&'a str> {
+    let prefix = format!(" {name}=\"");
+    svg.split(prefix.as_str()).skip(1).map(|rest| &rest[..rest.find('"').unwrap()]).collect()
+}
+
+/// The lines drawn in `svg`, as their two ends.
+fn svg_lines(svg: &str) -> Vec<((f64, f64), (f64, f64))> {
+    let coords = |name| attributes(svg, name).into_iter().map(|v| v.parse::<f64>().unwrap()).collect::<Vec<_>>();
+    let (x1, y1, x2, y2) = (coords("x1"), coords("y1"), coords("x2"), coords("y2"));
+    (0..x1.len()).map(|i| ((x1[i], y1[i]), (x2[i], y2[i]))).collect()
+}
+
+/// The corners of every polygon drawn in `svg`.
+fn svg_polygons(svg: &str) -> Vec<Vec<(f64, f64)>> {
+    attributes(svg, "points")
+        .into_iter()
+        .map(|points| {
+            let point = |p: &str| p.split_once(',').map(|(x, y)| (x.parse().unwrap(), y.parse().unwrap())).unwrap();
+            points.split(' ').map(point).collect()
+        })
+        .collect()
+}
+
+/// The least common multiple of `nums`, from the highest power of each prime dividing any of them.
+fn lcm_by_factoring(nums: &[u64]) -> u64 {
+    let mut result = 1;
+    for p in (2..1000).filter(|&p| (2..p).all(|d| p % d != 0)) {
+        let power = nums
+            .iter()
+            .map(|&n| {
+                let (mut n, mut power) = (n, 1);
+                while n % p == 0 {
+                    n /= p;
+                    power *= p;
+                }
+                power
+            })
+            .max()
+            .unwrap_or(1);
+        result *= power;
+    }
+    result
+}
+
+proptest! {
+    #[test]
+    fn neighbors8_are_the_eight_surrounding_cells(x in COORD, y in COORD) {
+        let neighbors = iter_neighbors8(x, y);
+        for (i, &(nx, ny)) in neighbors.iter().enumerate() {
+            prop_assert_eq!((nx - x).abs().max((ny - y).abs()), 1);
+            prop_assert!(!neighbors[..i].contains(&(nx, ny)));
+        }
+    }
+
+    #[test]
+    fn neighbors8_are_symmetric(x in COORD, y in COORD) {
+        for (nx, ny) in iter_neighbors8(x, y) {
+            prop_assert!(iter_neighbors8(nx, ny).contains(&(x, y)));
+        }
+    }
+
+    #[test]
+    fn bounded_neighbors8_are_the_unbounded_ones_in_bounds(x in COORD, y in COORD, max_x in 0..50, max_y in 0..50) {
+        let bounded = iter_neighbors_bounded8(x, y, max_x, max_y);
+        let expected = iter_neighbors8(x, y)
+            .into_iter()
+            .filter(|&(nx, ny)| (0..max_x).contains(&nx) && (0..max_y).contains(&ny))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(bounded, expected);
+    }
+
+    #[test]
+    fn neighbors4_are_the_orthogonal_neighbors8(x in -1000i64..1000, y in -1000i64..1000) {
+        let neighbors = iter_neighbors4(x, y);
+        let diagonal = iter_neighbors8(x as i32, y as i32);
+        for (i, &(nx, ny)) in neighbors.iter().enumerate() {
+            prop_assert_eq!((nx - x).abs() + (ny - y).abs(), 1);
+            prop_assert!(!neighbors[..i].contains(&(nx, ny)));
+            prop_assert!(diagonal.contains(&(nx as i32, ny as i32)));
+            prop_assert!(iter_neighbors4(nx, ny).contains(&(x, y)));
+        }
+    }
+
+    #[test]
+    fn bounded_neighbors4_are_the_unbounded_ones_in_bounds(
+        x in -1000i64..1000,
+        y in -1000i64..1000,
+        max_x in 0i64..50,
+        max_y in 0i64..50,
+    ) {
+        let bounded = iter_neighbors4_bounded(x, y, max_x, max_y);
+        let expected = iter_neighbors4(x, y)
+            .into_iter()
+            .filter(|&(nx, ny)| (0..max_x).contains(&nx) && (0..max_y).contains(&ny))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(bounded, expected);
+    }
+
+    #[test]
+    fn gcd_is_the_greatest_common_divisor(a in 1u64..1_000_000, b in 1u64..1_000_000) {
+        let g = gcd(a, b);
+        prop_assert_eq!(a % g, 0);
+        prop_assert_eq!(b % g, 0);
+        prop_assert_eq!(gcd(a / g, b / g), 1);
+        prop_assert_eq!(g, gcd(b, a));
+    }
+
+    #[test]
+    fn gcd_with_zero_is_the_other_number(a in any::<u64>()) {
+        prop_assert_eq!(gcd(a, 0), a);
+        prop_assert_eq!(gcd(0, a), a);
+    }
+
+    #[test]
+    fn lcm_is_the_least_common_multiple(nums in vec(1u64..1000, 1..6)) {
+        let l = lcm(&nums);
+        for n in &nums {
+            prop_assert_eq!(l % n, 0);
+        }
+        prop_assert_eq!(l, lcm_by_factoring(&nums));
+    }
+
+    #[test]
+    fn lcm_ignores_order(mut nums in vec(1u64..1000, 0..6)) {
+        let l = lcm(&nums);
+        nums.reverse();
+        prop_assert_eq!(lcm(&nums), l);
+    }
+
+    #[test]
+    fn lcm_does_not_overflow_when_the_answer_fits(a in 1u64..u64::MAX, k in 1u64..100) {
+        prop_assert_eq!(lcm(&[a, a]), a);
+        let multiple = a.saturating_mul(k) / k * k;
+        prop_assert_eq!(lcm(&[multiple, multiple / k]), multiple);
+    }
+
+    #[test]
+    fn lcm_with_zero_is_zero(nums in vec(0u64..1000, 1..6)) {
+        let mut nums = nums;
+        nums.push(0);
+        prop_assert_eq!(lcm(&nums), 0);
+    }
+
+    #[test]
+    fn automaton_finds_what_a_naive_search_finds(patterns in vec("[ab]{0,4}", 1..6), text in "[abc]{0,30}") {
+        let mut found = Automaton::new(&patterns).find_overlapping(&text).collect::<Vec<_>>();
+        found.sort_by_key(|m| (m.start, m.end, m.pattern));
+
+        let mut expected = vec![];
+        for (pattern, p) in patterns.iter().enumerate() {
+            if p.is_empty() {
+                continue;
+            }
+            for start in 0..=text.len().saturating_sub(p.len()) {
+                if text[start..].starts_with(p.as_str()) {
+                    expected.push(Match { pattern, start, end: start + p.len() });
+                }
+            }
+        }
+        expected.sort_by_key(|m| (m.start, m.end, m.pattern));
+
+        prop_assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn interval_len_counts_what_it_contains(start in END, end in END) {
+        let interval = Interval::new(start, end);
+        let contained = (-25..25).filter(|&x| interval.contains(x)).count() as i64;
+        prop_assert_eq!(interval.len(), contained);
+        prop_assert_eq!(interval.is_empty(), contained == 0);
+        prop_assert_eq!(Interval::with_len(start, interval.len()).len(), interval.len());
+    }
+
+    #[test]
+    fn interval_intersection_contains_what_both_contain(a in END, b in END, c in END, d in END) {
+        let (first, second) = (Interval::new(a, b), Interval::new(c, d));
+        let both = first.intersect(second);
+        prop_assert_eq!(both.is_empty(), second.intersect(first).is_empty());
+        if !both.is_empty() {
+            prop_assert_eq!(both, second.intersect(first));
+        }
+        for x in -25..25 {
+            prop_assert_eq!(both.contains(x), first.contains(x) && second.contains(x));
+        }
+    }
+
+    #[test]
+    fn interval_shift_moves_every_number(start in END, end in END, offset in -50i64..50) {
+        let interval = Interval::new(start, end);
+        let shifted = interval.shift(offset);
+        prop_assert_eq!(shifted.len(), interval.len());
+        for x in -25..25 {
+            prop_assert_eq!(shifted.contains(x + offset), interval.contains(x));
+        }
+        prop_assert_eq!(shifted.shift(-offset), interval);
+    }
+
+    #[test]
+    fn renderer_draws_the_window_with_later_overlays_on_top(
+        rows in vec("[.#]{4}", 0..6),
+        window in (0usize..8, 0usize..8, 0usize..6, 0usize..6),
+        first in vec((0usize..7, 0usize..5), 0..8),
+        second in vec((0usize..7, 0usize..5), 0..8),
+    ) {
+        let grid = rows.iter().map(|r| r.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+        let (row_start, row_end, col_start, col_end) = window;
+        let rendered = GridRenderer::new()
+            .overlay(first.iter().copied(), 'O')
+            .highlight(first.iter().copied(), Color::Red)
+            .overlay(second.iter().copied(), 'X')
+            .window(row_start..row_end, col_start..col_end)
+            .render_to_string(&grid);
+
+        let mut expected = String::new();
+        for (i, row) in grid.iter().enumerate().take(row_end).skip(row_start) {
+            for (j, &cell) in row.iter().enumerate().take(col_end).skip(col_start) {
+                expected.push(match (second.contains(&(i, j)), first.contains(&(i, j))) {
+                    (true, _) => 'X',
+                    (false, true) => 'O',
+                    (false, false) => cell,
+                });
+            }
+            expected.push('\n');
+        }
+        prop_assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn ray_draws_the_part_of_the_ray_inside_the_window(
+        origin in (-20i32..20, -20i32..20),
+        direction in (-4i32..5, -4i32..5),
+        min in -10i32..10,
+        len in 0i32..20,
+    ) {
+        prop_assume!(direction != (0, 0));
+        let (o, d) = ((origin.0 as f64, origin.1 as f64), (direction.0 as f64, direction.1 as f64));
+        let (min, max) = (min as f64, (min + len) as f64);
+        let mut svg = Svg::new();
+        let drawn = svg.ray(o, d, min, max, "black");
+        let lines = svg_lines(&svg.to_string());
+        prop_assert_eq!(lines.len(), usize::from(drawn));
+
+        let inside = |(x, y): (f64, f64)| {
+            (min - EPSILON..=max + EPSILON).contains(&x) && (min - EPSILON..=max + EPSILON).contains(&y)
+        };
+        // How far along the ray `p` is, in multiples of `direction`.
+        let along = |p: (f64, f64)| ((p.0 - o.0) * d.0 + (p.1 - o.1) * d.1) / (d.0 * d.0 + d.1 * d.1);
+        let on_ray = |p: (f64, f64)| ((p.0 - o.0) * d.1 - (p.1 - o.1) * d.0).abs() < EPSILON && along(p) > -EPSILON;
+
+        for &(from, to) in &lines {
+            prop_assert!(inside(from) && inside(to), "{:?} is not in the window", (from, to));
+            prop_assert!(on_ray(from) && on_ray(to), "{:?} is not on the ray", (from, to));
+        }
+        for t in (0..=400).map(|t| t as f64 / 4.0) {
+            let p = (o.0 + t * d.0, o.1 + t * d.1);
+            if (min..=max).contains(&p.0) && (min..=max).contains(&p.1) {
+                let &(from, to) = lines.first().ok_or_else(|| TestCaseError::fail(format!("{p:?} is in the window")))?;
+                prop_assert!((along(from) - EPSILON..=along(to) + EPSILON).contains(&t), "{:?} misses {:?}", (from, to), p);
+            }
+        }
+    }
+
+    #[test]
+    fn iso_box_moves_up_the_page_with_height_and_down_it_with_depth(
+        min in (-5i32..5, -5i32..5, -5i32..5),
+        size in (1i32..4, 1i32..4, 1i32..4),
+        k in -3i32..4,
+    ) {
+        let picture = |dx: i32, dz: i32| {
+            let low = ((min.0 + dx) as f64, (min.1 + dx) as f64, (min.2 + dz) as f64);
+            let high = (low.0 + size.0 as f64, low.1 + size.1 as f64, low.2 + size.2 as f64);
+            let mut svg = Svg::new();
+            svg.iso_box(low, high, "white");
+            svg_polygons(&svg.to_string())
+        };
+        let still = picture(0, 0);
+        prop_assert_eq!(still.len(), 3);
+        prop_assert!(still.iter().all(|face| face.len() == 4));
+
+        // One up in `z` is one up the page. One along both `x` and `y` is straight down it, by
+        // twice the sine of 30 degrees.
+        for (moved, dy) in [(picture(0, k), -k as f64), (picture(k, 0), k as f64)] {
+            for (face, moved_face) in still.iter().zip(&moved) {
+                for (&(x, y), &(mx, my)) in face.iter().zip(moved_face) {
+                    prop_assert!((mx - x).abs() < EPSILON && (my - y - dy).abs() < EPSILON);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn lcm_of_nothing_is_one() {
+    assert_eq!(lcm::<u64>(&[]), 1);
+}